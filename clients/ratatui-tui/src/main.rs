@@ -1,4 +1,4 @@
-﻿// Server configuration - change this to your local IP for network play
+// Server configuration - change this to your local IP for network play
 const SERVER_URL: &str = "http://localhost:3000";
 // For network play, use something like: "http://192.168.1.100:3000"
 
@@ -39,6 +39,9 @@ async fn main() -> Result<()> {
         app.messages
             .push(format!("Failed to get initial location: {}", e));
     }
+    if let Err(e) = check_mail(&mut app) {
+        app.messages.push(format!("Failed to check mail: {}", e));
+    }
 
     // Main loop
     let result = run_app(&mut terminal, &mut app).await;
@@ -76,6 +79,7 @@ async fn run_app(
 
                         // Parse and handle command
                         handle_command(&cmd, app).await?;
+                        check_mail(app)?;
                     }
                     _ => {}
                 }
@@ -99,10 +103,10 @@ async fn handle_command(cmd: &str, app: &mut AppState) -> Result<()> {
             let direction = parts[1];
 
             let output = Command::new("spacetime")
-                .args(&[
+                .args([
                     "call",
                     "--server",
-                    "SERVER_URL",
+                    SERVER_URL,
                     "dogmud",
                     "move_player",
                     direction,
@@ -139,13 +143,8 @@ async fn handle_command(cmd: &str, app: &mut AppState) -> Result<()> {
             let target_id = parts[1];
 
             let output = Command::new("spacetime")
-                .args(&[
-                    "call",
-                    "--server",
-                    "SERVER_URL",
-                    "dogmud",
-                    "attack",
-                    target_id,
+                .args([
+                    "call", "--server", SERVER_URL, "dogmud", "attack", target_id,
                 ])
                 .output()?;
 
@@ -184,15 +183,45 @@ async fn handle_command(cmd: &str, app: &mut AppState) -> Result<()> {
     Ok(())
 }
 
+// Refresh the unread count shown in the status bar
+fn check_mail(app: &mut AppState) -> Result<()> {
+    let output = Command::new("spacetime")
+        .args(["call", "--server", SERVER_URL, "dogmud", "check_mail"])
+        .output()?;
+
+    if !output.status.success() {
+        return Ok(());
+    }
+
+    let logs = Command::new("spacetime")
+        .args(["logs", "--server", SERVER_URL, "dogmud"])
+        .output()?;
+
+    if logs.status.success() {
+        let log_str = String::from_utf8_lossy(&logs.stdout);
+        let unread = log_str.lines().rev().find_map(|line| {
+            let start = line.find("<<<MAIL_UNREAD ")? + "<<<MAIL_UNREAD ".len();
+            let end = line[start..].find(">>>")? + start;
+            line[start..end].parse().ok()
+        });
+
+        if let Some(unread) = unread {
+            app.unread_mail = unread;
+        }
+    }
+
+    Ok(())
+}
+
 // Separate function to avoid recursion issues
 async fn do_look(app: &mut AppState) -> Result<()> {
     let output = Command::new("spacetime")
-        .args(&["call", "--server", "SERVER_URL", "dogmud", "look"])
+        .args(["call", "--server", SERVER_URL, "dogmud", "look"])
         .output()?;
 
     if output.status.success() {
         let logs = Command::new("spacetime")
-            .args(&["logs", "--server", "http://localhost:3000", "dogmud"])
+            .args(["logs", "--server", SERVER_URL, "dogmud"])
             .output()?;
 
         if logs.status.success() {
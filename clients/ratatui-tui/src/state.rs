@@ -5,6 +5,7 @@
     pub max_hp: i32,
     pub stamina: i32,
     pub max_stamina: i32,
+    pub unread_mail: usize,
 }
 
 impl AppState {
@@ -20,6 +21,7 @@ impl AppState {
             max_hp: 100,
             stamina: 100,
             max_stamina: 100,
+            unread_mail: 0,
         }
     }
 }
@@ -2,7 +2,7 @@
 use ratatui::{
     layout::{Constraint, Direction, Layout},
     style::{Color, Style},
-    text::{Line, Text},
+    text::Line,
     widgets::{Block, Borders, Paragraph},
     Frame,
 };
@@ -15,7 +15,7 @@ pub fn render_ui(f: &mut Frame, app: &AppState) {
             Constraint::Percentage(10), // Gauges
             Constraint::Percentage(10), // Input
         ])
-        .split(f.size());
+        .split(f.area());
 
     // Main text buffer - show only the last N messages that fit
     let available_height = chunks[0].height.saturating_sub(2) as usize; // Subtract borders
@@ -32,10 +32,14 @@ pub fn render_ui(f: &mut Frame, app: &AppState) {
         Paragraph::new(lines).block(Block::default().title("DOGMUD").borders(Borders::ALL));
     f.render_widget(buffer, chunks[0]);
 
-    // HP/Stamina gauges (rest stays the same)
+    // HP/Stamina gauges and the mail indicator
     let gauges_layout = Layout::default()
         .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .constraints([
+            Constraint::Percentage(40),
+            Constraint::Percentage(40),
+            Constraint::Percentage(20),
+        ])
         .split(chunks[1]);
 
     let hp_ratio = app.hp as f64 / app.max_hp as f64;
@@ -52,6 +56,16 @@ pub fn render_ui(f: &mut Frame, app: &AppState) {
         .ratio(stamina_ratio);
     f.render_widget(stamina_gauge, gauges_layout[1]);
 
+    let mail_style = if app.unread_mail > 0 {
+        Style::default().fg(Color::Green)
+    } else {
+        Style::default()
+    };
+    let mail = Paragraph::new(format!("{} unread", app.unread_mail))
+        .style(mail_style)
+        .block(Block::default().title("Mail").borders(Borders::ALL));
+    f.render_widget(mail, gauges_layout[2]);
+
     // Input
     let input =
         Paragraph::new(format!("> {}", app.input)).block(Block::default().borders(Borders::ALL));
@@ -11,3 +11,129 @@ pub fn calculate_base_damage(weapon_damage: u16, strength: u8, skill: u8) -> u16
     let multiplier = calculate_damage_multiplier(strength, skill);
     (weapon_damage as f32 * multiplier) as u16
 }
+
+/// Damage left after armor absorbs its share (always at least 1)
+pub fn apply_armor(damage: u16, armor: u16) -> u16 {
+    damage.saturating_sub(armor).max(1)
+}
+
+/// Damage after a resistance multiplier (0.0 immune, 1.0 normal, 2.0 double)
+pub fn apply_resistance(damage: u16, multiplier: f32) -> u16 {
+    (damage as f32 * multiplier.max(0.0)).round() as u16
+}
+
+/// Attack roll modifier for a shot `distance` rooms away
+/// Full accuracy next door, 15% worse for each room beyond, never below half
+pub fn range_modifier(distance: u8) -> f32 {
+    (1.0 - distance.saturating_sub(1) as f32 * 0.15).max(0.5)
+}
+
+/// Time between automatic swings in milliseconds
+/// Three seconds at attack speed 1.0 and DEX 100; faster weapons and hands
+/// swing more often, never quicker than every half second
+pub fn attack_interval_ms(attack_speed: f32, dexterity: u8) -> u32 {
+    let speed = if attack_speed > 0.0 {
+        attack_speed
+    } else {
+        1.0
+    };
+    let dex_factor = (1.5 - dexterity as f32 / 200.0).max(0.5);
+    ((3000.0 / speed * dex_factor) as u32).max(500)
+}
+
+/// Chance to break away from a fight: 50% against an equally quick opponent
+pub fn flee_chance(dexterity: u8, opponent_dexterity: u8) -> f32 {
+    (0.5 + (dexterity as f32 - opponent_dexterity as f32) / 200.0).clamp(0.1, 0.9)
+}
+
+/// Index picked by a roll in 0..sum(weights), e.g. for hit locations
+pub fn pick_weighted(weights: &[u32], roll: u32) -> usize {
+    let mut remaining = roll;
+    for (index, weight) in weights.iter().enumerate() {
+        if remaining < *weight {
+            return index;
+        }
+        remaining -= weight;
+    }
+    weights.len().saturating_sub(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base_damage() {
+        // Mace from the TDD: 50 * (95 + 12) / 100 = 53.5 -> 53
+        assert_eq!(calculate_base_damage(50, 95, 12), 53);
+
+        // No strength or skill means no damage
+        assert_eq!(calculate_base_damage(50, 0, 0), 0);
+    }
+
+    #[test]
+    fn test_apply_armor() {
+        assert_eq!(apply_armor(20, 5), 15);
+
+        // Heavy armor never reduces a hit below 1
+        assert_eq!(apply_armor(5, 20), 1);
+    }
+
+    #[test]
+    fn test_apply_resistance() {
+        assert_eq!(apply_resistance(20, 1.0), 20);
+        assert_eq!(apply_resistance(20, 0.5), 10);
+        assert_eq!(apply_resistance(20, 1.5), 30);
+
+        // Immunity and nonsense multipliers
+        assert_eq!(apply_resistance(20, 0.0), 0);
+        assert_eq!(apply_resistance(20, -1.0), 0);
+    }
+
+    #[test]
+    fn test_range_modifier() {
+        assert!((range_modifier(1) - 1.0).abs() < 0.01);
+        assert!((range_modifier(2) - 0.85).abs() < 0.01);
+        assert!((range_modifier(3) - 0.7).abs() < 0.01);
+
+        // Long shots bottom out at half accuracy
+        assert!((range_modifier(10) - 0.5).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_attack_interval() {
+        assert_eq!(attack_interval_ms(1.0, 100), 3000);
+
+        // Unarmed (speed 0) swings like a speed 1.0 weapon
+        assert_eq!(attack_interval_ms(0.0, 100), 3000);
+
+        // Quick dagger, clumsy hands
+        assert_eq!(attack_interval_ms(1.5, 100), 2000);
+        assert_eq!(attack_interval_ms(1.0, 0), 4500);
+
+        // Floor of half a second
+        assert_eq!(attack_interval_ms(10.0, 255), 500);
+    }
+
+    #[test]
+    fn test_flee_chance() {
+        assert!((flee_chance(100, 100) - 0.5).abs() < 0.01);
+        assert!((flee_chance(150, 100) - 0.75).abs() < 0.01);
+        assert!((flee_chance(255, 0) - 0.9).abs() < 0.01);
+        assert!((flee_chance(0, 255) - 0.1).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_pick_weighted() {
+        let weights = [10, 45, 10, 25, 10];
+        assert_eq!(pick_weighted(&weights, 0), 0);
+        assert_eq!(pick_weighted(&weights, 9), 0);
+        assert_eq!(pick_weighted(&weights, 10), 1);
+        assert_eq!(pick_weighted(&weights, 54), 1);
+        assert_eq!(pick_weighted(&weights, 55), 2);
+        assert_eq!(pick_weighted(&weights, 99), 4);
+
+        // Out-of-range rolls land on the last entry
+        assert_eq!(pick_weighted(&weights, 500), 4);
+    }
+}
@@ -0,0 +1,37 @@
+// Crafting formulas
+
+/// Quality multiplier for crafted goods from how far the roll beat the recipe
+/// A bare success gives 0.8, double the difficulty or better gives the 1.3 cap
+pub fn craft_quality(roll: f32, difficulty: u8) -> f32 {
+    let margin = roll / difficulty.max(1) as f32;
+    (0.8 + (margin - 1.0) * 0.5).clamp(0.8, 1.3)
+}
+
+/// Scale an item stat by crafting quality
+pub fn apply_quality(value: u16, quality: f32) -> u16 {
+    (value as f32 * quality).round().min(u16::MAX as f32) as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_craft_quality() {
+        // Roll exactly at difficulty
+        assert!((craft_quality(50.0, 50) - 0.8).abs() < 0.001);
+
+        // 50% over: 0.8 + 0.5 * 0.5 = 1.05
+        assert!((craft_quality(75.0, 50) - 1.05).abs() < 0.001);
+
+        // Capped
+        assert!((craft_quality(500.0, 50) - 1.3).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_apply_quality() {
+        assert_eq!(apply_quality(100, 0.8), 80);
+        assert_eq!(apply_quality(100, 1.3), 130);
+        assert_eq!(apply_quality(60_000, 1.3), u16::MAX);
+    }
+}
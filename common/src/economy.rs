@@ -0,0 +1,128 @@
+// Shop pricing formulas
+
+/// Fraction of durability remaining, 1.0 for items without durability
+pub fn durability_fraction(durability: u16, max_durability: u16) -> f32 {
+    if max_durability == 0 {
+        return 1.0;
+    }
+    (durability as f32 / max_durability as f32).clamp(0.0, 1.0)
+}
+
+/// Item value after wear: a broken item is still worth half its base value
+pub fn condition_value(base_value: u32, durability: u16, max_durability: u16) -> f32 {
+    base_value as f32 * (0.5 + 0.5 * durability_fraction(durability, max_durability))
+}
+
+/// Price a shopkeeper charges the buyer
+/// Markup: 1.25 at Haggling 0, down to 1.0 at Haggling 100
+pub fn buy_price(base_value: u32, durability: u16, max_durability: u16, haggling: u8) -> u32 {
+    let markup = 1.25 - haggling.min(100) as f32 / 400.0;
+    let price = condition_value(base_value, durability, max_durability) * markup;
+    (price.round() as u32).max(1)
+}
+
+/// Price a shopkeeper pays the seller
+/// Payout: 40% of value at Haggling 0, up to 60% at Haggling 100
+pub fn sell_price(base_value: u32, durability: u16, max_durability: u16, haggling: u8) -> u32 {
+    let payout = 0.4 + haggling.min(100) as f32 / 500.0;
+    let price = condition_value(base_value, durability, max_durability) * payout;
+    price.floor() as u32
+}
+
+/// Blacksmith's fee to restore an item to full durability
+/// Half the base value for a fully broken item, pro rata otherwise
+pub fn repair_cost(base_value: u32, durability: u16, max_durability: u16) -> u32 {
+    let missing = 1.0 - durability_fraction(durability, max_durability);
+    if missing <= 0.0 {
+        return 0;
+    }
+    ((base_value as f32 * 0.5 * missing).ceil() as u32).max(1)
+}
+
+/// Chance that a repair permanently lowers max durability
+/// 50% for an unskilled smith, down to 5% at Blacksmithing 90+
+pub fn repair_wear_chance(blacksmithing: u8) -> f32 {
+    (0.5 - blacksmithing as f32 / 200.0).max(0.05)
+}
+
+/// Max durability lost when a repair wears the item down (5%, at least 1)
+pub fn repair_wear_amount(max_durability: u16) -> u16 {
+    (max_durability / 20).max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_durability_fraction() {
+        assert!((durability_fraction(50, 100) - 0.5).abs() < 0.01);
+        assert!((durability_fraction(0, 0) - 1.0).abs() < 0.01);
+        assert!((durability_fraction(150, 100) - 1.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_buy_price() {
+        // Full durability, no haggling: 100 * 1.25 = 125
+        assert_eq!(buy_price(100, 100, 100, 0), 125);
+
+        // Haggling 100: 100 * 1.0 = 100
+        assert_eq!(buy_price(100, 100, 100, 100), 100);
+
+        // Half durability: 100 * 0.75 * 1.25 = 93.75 -> 94
+        assert_eq!(buy_price(100, 50, 100, 0), 94);
+
+        // Never free
+        assert_eq!(buy_price(0, 0, 0, 100), 1);
+    }
+
+    #[test]
+    fn test_sell_price() {
+        // Full durability, no haggling: 100 * 0.4 = 40
+        assert_eq!(sell_price(100, 100, 100, 0), 40);
+
+        // Haggling 100: 100 * 0.6 = 60
+        assert_eq!(sell_price(100, 100, 100, 100), 60);
+
+        // Broken item: 100 * 0.5 * 0.4 = 20
+        assert_eq!(sell_price(100, 0, 100, 0), 20);
+    }
+
+    #[test]
+    fn test_repair_cost() {
+        // Fully broken: 100 * 0.5 = 50
+        assert_eq!(repair_cost(100, 0, 100), 50);
+
+        // Quarter worn: 100 * 0.5 * 0.25 = 12.5 -> 13
+        assert_eq!(repair_cost(100, 75, 100), 13);
+
+        // Nothing to fix
+        assert_eq!(repair_cost(100, 100, 100), 0);
+
+        // Cheap items still cost something
+        assert_eq!(repair_cost(1, 99, 100), 1);
+    }
+
+    #[test]
+    fn test_repair_wear() {
+        assert!((repair_wear_chance(0) - 0.5).abs() < 0.01);
+        assert!((repair_wear_chance(50) - 0.25).abs() < 0.01);
+        assert!((repair_wear_chance(200) - 0.05).abs() < 0.01);
+
+        assert_eq!(repair_wear_amount(100), 5);
+        assert_eq!(repair_wear_amount(10), 1);
+    }
+
+    #[test]
+    fn test_no_arbitrage() {
+        // Selling back must never pay more than buying cost
+        for haggling in [0u8, 50, 100, 255] {
+            for durability in [0u16, 25, 100] {
+                assert!(
+                    sell_price(100, durability, 100, haggling)
+                        < buy_price(100, durability, 100, haggling)
+                );
+            }
+        }
+    }
+}
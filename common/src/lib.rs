@@ -1,4 +1,16 @@
-﻿pub mod stats;
+﻿pub mod combat;
+pub mod crafting;
+pub mod economy;
+pub mod limits;
+pub mod names;
+pub mod regen;
+pub mod stats;
 
-// Only export stats (which already has these functions)
+// Re-export the pure formulas used by the server
+pub use combat::*;
+pub use crafting::*;
+pub use economy::*;
+pub use limits::*;
+pub use names::*;
+pub use regen::*;
 pub use stats::*;
@@ -0,0 +1,67 @@
+// Sliding-window rate limiting
+
+/// Move a fixed window forward to `now`, carrying the count of the window
+/// just finished into `previous`. Returns (window_start, previous, current).
+pub fn advance_window(
+    window_start: i64,
+    previous: u32,
+    current: u32,
+    now: i64,
+    window: i64,
+) -> (i64, u32, u32) {
+    let elapsed = now - window_start;
+    if elapsed < window {
+        (window_start, previous, current)
+    } else if elapsed < window * 2 {
+        (window_start + window, current, 0)
+    } else {
+        // Quiet for a whole window or more: nothing carries over
+        (now, 0, 0)
+    }
+}
+
+/// Actions counted over the last `window`, weighting the previous window by
+/// how much of it still overlaps
+pub fn sliding_window_count(
+    window_start: i64,
+    previous: u32,
+    current: u32,
+    now: i64,
+    window: i64,
+) -> f32 {
+    if window <= 0 {
+        return current as f32;
+    }
+    let elapsed = (now - window_start).clamp(0, window) as f32 / window as f32;
+    previous as f32 * (1.0 - elapsed) + current as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_advance_window() {
+        // Still inside the window
+        assert_eq!(advance_window(0, 3, 2, 500, 1000), (0, 3, 2));
+
+        // Into the next window: current becomes previous
+        assert_eq!(advance_window(0, 3, 2, 1500, 1000), (1000, 2, 0));
+
+        // Idle for longer than a window
+        assert_eq!(advance_window(0, 3, 2, 5000, 1000), (5000, 0, 0));
+    }
+
+    #[test]
+    fn test_sliding_window_count() {
+        // Start of a window: all of the previous window still counts
+        assert!((sliding_window_count(1000, 4, 1, 1000, 1000) - 5.0).abs() < 0.01);
+
+        // Halfway through: half of it does
+        assert!((sliding_window_count(1000, 4, 1, 1500, 1000) - 3.0).abs() < 0.01);
+
+        // Bursting at a window edge is still caught
+        let (start, previous, current) = advance_window(0, 0, 5, 1001, 1000);
+        assert!(sliding_window_count(start, previous, current, 1001, 1000) >= 4.9);
+    }
+}
@@ -0,0 +1,91 @@
+/// Names no player may take, compared case-insensitively
+pub const RESERVED_NAMES: &[&str] = &[
+    "admin",
+    "administrator",
+    "moderator",
+    "staff",
+    "system",
+    "server",
+    "god",
+    "someone",
+    "something",
+    "you",
+    "everyone",
+];
+
+/// Words that may not appear anywhere in a name
+pub const BLOCKED_WORDS: &[&str] = &["fuck", "shit", "cunt", "bitch", "whore", "slut", "nazi"];
+
+pub const NAME_MIN_LEN: usize = 3;
+pub const NAME_MAX_LEN: usize = 16;
+
+/// Check a character name and return it capitalised ("aldric" -> "Aldric")
+/// `extra_blocked` adds server-configured words to the built-in list
+pub fn validate_character_name(name: &str, extra_blocked: &[String]) -> Result<String, String> {
+    let name = name.trim();
+    let len = name.chars().count();
+
+    if !(NAME_MIN_LEN..=NAME_MAX_LEN).contains(&len) {
+        return Err(format!(
+            "Names must be {} to {} letters long",
+            NAME_MIN_LEN, NAME_MAX_LEN
+        ));
+    }
+
+    // Letters, with an apostrophe or hyphen allowed between them
+    let chars: Vec<char> = name.chars().collect();
+    for (i, c) in chars.iter().enumerate() {
+        let joiner = (*c == '\'' || *c == '-')
+            && i > 0
+            && i + 1 < chars.len()
+            && chars[i - 1].is_ascii_alphabetic()
+            && chars[i + 1].is_ascii_alphabetic();
+        if !c.is_ascii_alphabetic() && !joiner {
+            return Err("Names may only contain letters".to_string());
+        }
+    }
+
+    let lower = name.to_ascii_lowercase();
+    if RESERVED_NAMES.contains(&lower.as_str()) {
+        return Err(format!("'{}' is reserved", name));
+    }
+
+    let blocked = BLOCKED_WORDS
+        .iter()
+        .copied()
+        .chain(extra_blocked.iter().map(|w| w.as_str()))
+        .map(|w| w.trim().to_ascii_lowercase())
+        .any(|w| !w.is_empty() && lower.contains(&w));
+    if blocked {
+        return Err("That name is not allowed".to_string());
+    }
+
+    let mut canonical = lower;
+    canonical[..1].make_ascii_uppercase();
+    Ok(canonical)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_character_name() {
+        assert_eq!(validate_character_name("aldric", &[]).unwrap(), "Aldric");
+        assert_eq!(
+            validate_character_name(" O'Brien ", &[]).unwrap(),
+            "O'brien"
+        );
+
+        // Length, characters and joiner placement
+        assert!(validate_character_name("Al", &[]).is_err());
+        assert!(validate_character_name("Aldric the Bold", &[]).is_err());
+        assert!(validate_character_name("R2D2", &[]).is_err());
+        assert!(validate_character_name("-Aldric", &[]).is_err());
+
+        // Reserved names and blocked words, built in or configured
+        assert!(validate_character_name("Admin", &[]).is_err());
+        assert!(validate_character_name("Shithead", &[]).is_err());
+        assert!(validate_character_name("Dogbert", &["bert".to_string()]).is_err());
+    }
+}
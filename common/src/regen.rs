@@ -0,0 +1,66 @@
+// Derived pools and regeneration rates
+
+/// Maximum HP: VIT × 10
+pub fn max_hp(vitality: u8) -> i32 {
+    vitality as i32 * 10
+}
+
+/// Maximum mana: (PER + WIL) × 5
+pub fn max_mana(perception: u8, willpower: u8) -> i32 {
+    (perception as i32 + willpower as i32) * 5
+}
+
+/// HP recovered per regeneration tick: 1% of max HP, at least 1
+pub fn hp_regen(vitality: u8) -> i32 {
+    (max_hp(vitality) / 100).max(1)
+}
+
+/// Stamina recovered per regeneration tick, driven by vitality and willpower
+pub fn stamina_regen(vitality: u8, willpower: u8) -> i32 {
+    ((vitality as i32 + willpower as i32) / 20).max(1)
+}
+
+/// Mana recovered per regeneration tick: 1% of max mana, at least 1
+pub fn mana_regen(perception: u8, willpower: u8) -> i32 {
+    (max_mana(perception, willpower) / 100).max(1)
+}
+
+/// Apply a rate multiplier (resting, safe zones, conditions) to a regen amount
+pub fn scale_regen(amount: i32, multiplier: f32) -> i32 {
+    (amount as f32 * multiplier.max(0.0)).round() as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_max_pools() {
+        // From the TDD stat table
+        assert_eq!(max_hp(100), 1000);
+        assert_eq!(max_hp(0), 0);
+        assert_eq!(max_mana(100, 100), 1000);
+        assert_eq!(max_mana(80, 120), 1000);
+        assert_eq!(max_mana(255, 255), 2550);
+    }
+
+    #[test]
+    fn test_regen_rates() {
+        assert_eq!(hp_regen(100), 10);
+        assert_eq!(stamina_regen(100, 100), 10);
+        assert_eq!(mana_regen(100, 100), 10);
+
+        // Even the feeblest recover something
+        assert_eq!(hp_regen(1), 1);
+        assert_eq!(stamina_regen(0, 0), 1);
+        assert_eq!(mana_regen(0, 0), 1);
+    }
+
+    #[test]
+    fn test_scale_regen() {
+        assert_eq!(scale_regen(10, 2.0), 20);
+        assert_eq!(scale_regen(10, 1.5), 15);
+        assert_eq!(scale_regen(10, 0.0), 0);
+        assert_eq!(scale_regen(10, -1.0), 0);
+    }
+}
@@ -33,6 +33,77 @@ pub fn is_critical_fail(attacker_roll: f32, defender_roll: f32) -> bool {
     attacker_roll <= threshold + 0.001
 }
 
+/// Chance that using a skill raises it a level: P = max(0.01, base_rate / level)
+/// base_rate 1.2 gives the TDD's 10% at level 12
+pub fn improvement_chance(level: u8) -> f32 {
+    (1.2 / level.max(1) as f32).clamp(0.01, 1.0)
+}
+
+/// Difficulty of reading a trail: 20 for fresh tracks, up to 100 as they fade
+pub fn tracking_dc(freshness: f32) -> f32 {
+    20.0 + (1.0 - freshness.clamp(0.0, 1.0)) * 80.0
+}
+
+/// Time a step takes (ms) for a given load (carried weight / capacity)
+/// 500ms up to half capacity, 1500ms when full, climbing steeply beyond that
+pub fn move_delay_ms(load_ratio: f32) -> u32 {
+    let load = load_ratio.max(0.0);
+    let delay = if load <= 0.5 {
+        500.0
+    } else if load <= 1.0 {
+        500.0 + (load - 0.5) * 2000.0
+    } else {
+        1500.0 + (load - 1.0) * 4000.0
+    };
+    delay.min(10_000.0) as u32
+}
+
+/// Mean of a freshly rolled stat; the spread comes from calculate_std_dev
+pub const STARTING_STAT_MEAN: f32 = 100.0;
+
+/// Roll a starting stat from N(100, 15) using two uniform samples in [0, 1)
+/// (Box-Muller), kept within three standard deviations
+pub fn rolled_stat(u1: f32, u2: f32) -> u8 {
+    let std_dev = calculate_std_dev(STARTING_STAT_MEAN);
+    let radius = (-2.0 * (1.0 - u1).max(f32::MIN_POSITIVE).ln()).sqrt();
+    let z = radius * (std::f32::consts::TAU * u2).cos();
+
+    let limit = 3.0 * std_dev;
+    (STARTING_STAT_MEAN + (z * std_dev).clamp(-limit, limit)).round() as u8
+}
+
+/// Point-buy: the five stats must add up to the rolled mean, each within 70..=130
+pub const POINT_BUY_TOTAL: u32 = 500;
+pub const POINT_BUY_MIN: u8 = 70;
+pub const POINT_BUY_MAX: u8 = 130;
+
+/// Check a point-buy spread (dexterity, strength, vitality, perception, willpower)
+pub fn check_point_buy(stats: &[u8]) -> Result<(), String> {
+    if stats.len() != 5 {
+        return Err("Give exactly five stats: DEX STR VIT PER WIL".to_string());
+    }
+
+    if stats
+        .iter()
+        .any(|&s| !(POINT_BUY_MIN..=POINT_BUY_MAX).contains(&s))
+    {
+        return Err(format!(
+            "Each stat must be between {} and {}",
+            POINT_BUY_MIN, POINT_BUY_MAX
+        ));
+    }
+
+    let total: u32 = stats.iter().map(|&s| s as u32).sum();
+    if total != POINT_BUY_TOTAL {
+        return Err(format!(
+            "Stats must add up to {} (you have {})",
+            POINT_BUY_TOTAL, total
+        ));
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -83,6 +154,18 @@ mod tests {
         assert!(!is_critical_fail(100.0, 100.0));
     }
 
+    #[test]
+    fn test_improvement_chance() {
+        // Level 12: 1.2 / 12 = 10%
+        assert!((improvement_chance(12) - 0.1).abs() < 0.001);
+
+        // Untrained skills always improve
+        assert!((improvement_chance(0) - 1.0).abs() < 0.001);
+
+        // Floor of 1% at high levels
+        assert!((improvement_chance(250) - 0.01).abs() < 0.001);
+    }
+
     #[test]
     fn test_stat_weighting() {
         // stat=100, skill=0: 100 * 0.7 = 70
@@ -96,4 +179,50 @@ mod tests {
         // Stats are more important (70% vs 30%)
         assert!(high_stat > high_skill * 2.0);
     }
+
+    #[test]
+    fn test_tracking_dc() {
+        assert!((tracking_dc(1.0) - 20.0).abs() < 0.01);
+        assert!((tracking_dc(0.5) - 60.0).abs() < 0.01);
+        assert!((tracking_dc(0.0) - 100.0).abs() < 0.01);
+
+        // Out-of-range freshness is clamped
+        assert!((tracking_dc(2.0) - 20.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_move_delay() {
+        assert_eq!(move_delay_ms(0.0), 500);
+        assert_eq!(move_delay_ms(0.5), 500);
+        assert_eq!(move_delay_ms(0.75), 1000);
+        assert_eq!(move_delay_ms(1.0), 1500);
+        assert_eq!(move_delay_ms(1.5), 3500);
+        assert_eq!(move_delay_ms(100.0), 10_000);
+    }
+
+    #[test]
+    fn test_rolled_stat() {
+        // No spread when the radius is zero
+        assert_eq!(rolled_stat(0.0, 0.3), 100);
+
+        // u1 = 1 - e^-0.5 gives a radius of 1: one std dev either side
+        let u1 = 1.0 - (-0.5f32).exp();
+        assert_eq!(rolled_stat(u1, 0.0), 115);
+        assert_eq!(rolled_stat(u1, 0.5), 85);
+
+        // Extreme samples are held to three std devs
+        assert_eq!(rolled_stat(0.999_999, 0.0), 145);
+        assert_eq!(rolled_stat(0.999_999, 0.5), 55);
+    }
+
+    #[test]
+    fn test_point_buy() {
+        assert!(check_point_buy(&[100, 100, 100, 100, 100]).is_ok());
+        assert!(check_point_buy(&[130, 70, 100, 110, 90]).is_ok());
+
+        // Wrong total, out of range, wrong count
+        assert!(check_point_buy(&[110, 100, 100, 100, 100]).is_err());
+        assert!(check_point_buy(&[140, 60, 100, 100, 100]).is_err());
+        assert!(check_point_buy(&[100, 100, 100, 100]).is_err());
+    }
 }
@@ -11,7 +11,8 @@ pub use tables::*;
 
 // Lifecycle hooks
 #[reducer(init)]
-pub fn init(_ctx: &ReducerContext) {
+pub fn init(ctx: &ReducerContext) {
+    create_default_channels(ctx);
     log::info!("DOGMUD server module initialized");
 }
 
@@ -21,6 +22,8 @@ pub fn client_connected(_ctx: &ReducerContext) {
 }
 
 #[reducer(client_disconnected)]
-pub fn client_disconnected(_ctx: &ReducerContext) {
+pub fn client_disconnected(ctx: &ReducerContext) {
+    // The character stays in the world, linkdead, until the next login
+    utils::session::end_caller_session(ctx);
     log::info!("Client disconnected");
 }
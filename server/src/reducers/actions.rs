@@ -0,0 +1,143 @@
+use crate::reducers::{
+    check_attack, check_cast, check_move, check_shoot, check_taunt, perform_attack, perform_cast,
+    perform_move, perform_shoot, perform_taunt,
+};
+use crate::tables::{
+    action_queue::action_queue, // ← Add trait import
+    entity::entity,             // ← Add trait import
+    room::room,                 // ← Add trait import
+    ActionKind,
+    Entity,
+    QueuedAction,
+    TickKind,
+};
+use crate::utils::{session, ticks, timing};
+use spacetimedb::{reducer, ReducerContext};
+
+/// Forget every action you had lined up
+#[reducer]
+pub fn clear_queue(ctx: &ReducerContext) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+
+    let cleared = timing::clear_queue(ctx, player.id);
+    if cleared == 0 {
+        return Err("You have nothing planned".to_string());
+    }
+
+    log::info!("{} cleared {} queued actions", player.name, cleared);
+    Ok(())
+}
+
+/// Fast tick: start the next queued action of everyone in a region who is ready
+#[reducer]
+pub fn tick_actions(ctx: &ReducerContext, region_id: u64) {
+    if !ticks::claim(ctx, region_id, TickKind::Actions) {
+        return;
+    }
+
+    let entity_ids: Vec<_> = ctx
+        .db
+        .room()
+        .region_id()
+        .filter(region_id)
+        .flat_map(|room| {
+            ctx.db
+                .entity()
+                .room_id()
+                .filter(room.id)
+                .collect::<Vec<_>>()
+        })
+        .map(|e| e.id)
+        .collect();
+
+    for entity_id in entity_ids {
+        // Only the front of each entity's queue
+        let Some(action) = timing::queued(ctx, entity_id).into_iter().next() else {
+            continue;
+        };
+
+        // Re-read: whoever they follow may have moved them earlier this tick
+        let Some(entity) = ctx.db.entity().id().find(entity_id) else {
+            continue;
+        };
+
+        if timing::action_ready_in(ctx, &entity, &action) > 0 {
+            continue;
+        }
+
+        ctx.db.action_queue().id().delete(action.id);
+
+        // This tick doesn't roll back on error, so an action that no longer
+        // passes its checks is dropped without running any of it
+        let name = entity.name.clone();
+        let kind = action.kind;
+        if let Err(e) = check(ctx, &entity, &action) {
+            log::info!("{}'s queued {:?} failed: {}", name, kind, e);
+            continue;
+        }
+
+        if let Err(e) = perform(ctx, entity, action) {
+            log::error!("{}'s queued {:?} failed part way: {}", name, kind, e);
+        }
+    }
+}
+
+fn check(ctx: &ReducerContext, entity: &Entity, action: &QueuedAction) -> Result<(), String> {
+    match action.kind {
+        ActionKind::Attack => {
+            check_attack(ctx, entity, action.target_id.ok_or("No target")?).map(|_| ())
+        }
+        ActionKind::Move => check_move(
+            ctx,
+            entity,
+            action.direction.as_deref().ok_or("No direction")?,
+        )
+        .map(|_| ()),
+        ActionKind::Cast => check_cast(
+            ctx,
+            entity,
+            action.spell_id.ok_or("No spell")?,
+            action.target_id,
+        )
+        .map(|_| ()),
+        ActionKind::Shoot => check_shoot(
+            ctx,
+            entity,
+            action.target_id.ok_or("No target")?,
+            action.direction.as_deref().ok_or("No direction")?,
+        )
+        .map(|_| ()),
+        ActionKind::Taunt => {
+            check_taunt(ctx, entity, action.target_id.ok_or("No target")?).map(|_| ())
+        }
+    }
+}
+
+fn perform(ctx: &ReducerContext, entity: Entity, action: QueuedAction) -> Result<(), String> {
+    match action.kind {
+        ActionKind::Attack => perform_attack(
+            ctx,
+            entity,
+            action.target_id.ok_or("No target")?,
+            action.location,
+        ),
+        ActionKind::Move => perform_move(
+            ctx,
+            entity,
+            action.direction.as_deref().ok_or("No direction")?,
+        ),
+        ActionKind::Cast => perform_cast(
+            ctx,
+            entity,
+            action.spell_id.ok_or("No spell")?,
+            action.target_id,
+        ),
+        ActionKind::Shoot => perform_shoot(
+            ctx,
+            entity,
+            action.target_id.ok_or("No target")?,
+            action.direction.as_deref().ok_or("No direction")?,
+        ),
+        ActionKind::Taunt => perform_taunt(ctx, entity, action.target_id.ok_or("No target")?),
+    }
+}
@@ -1,14 +1,156 @@
 use crate::tables::{
     account::account,               // ← Add trait import
+    chat::chat_ignore,              // ← Add trait import
+    chat::chat_membership,          // ← Add trait import
+    condition::condition,           // ← Add trait import
     entity::entity,                 // ← Add trait import
+    group::follower,                // ← Add trait import
+    item_data::item_data,           // ← Add trait import
+    item_template::item_template,   // ← Add trait import
+    mail::mail,                     // ← Add trait import
+    mail::mail_attachment,          // ← Add trait import
     player_session::player_session, // ← Add trait import
+    region::region,                 // ← Add trait import
+    room::room,                     // ← Add trait import
+    skill::skill,                   // ← Add trait import
+    starting_kit::starting_kit,     // ← Add trait import
+    starting_kit::starting_skill,   // ← Add trait import
+    stealth::stealth_state,         // ← Add trait import
+    trade::trade_session,           // ← Add trait import
+    trail::trail,                   // ← Add trait import
     Account,
     ClientType,
     Entity,
     EntityType,
+    EquipSlot,
+    GoldFlow,
+    ItemType,
     PlayerSession,
+    Position,
+    Skill,
 };
-use spacetimedb::{reducer, ReducerContext, Table};
+use crate::utils::{combat, config, groups, inventory, ledger, session, stealth, threat, timing};
+use dogmud_common::{check_point_buy, max_hp, max_mana, rolled_stat, validate_character_name};
+use spacetimedb::{rand::Rng, reducer, ReducerContext, Table};
+
+/// Sessions with no heartbeat for this long are closed
+const DEFAULT_SESSION_TIMEOUT_SECS: f32 = 300.0;
+
+/// Closed sessions are kept a day for reference, then removed
+const CLOSED_SESSION_RETENTION_MICROS: i64 = 86_400_000_000;
+
+/// Characters an account may hold unless `characters.max_per_account` says otherwise
+const DEFAULT_MAX_CHARACTERS: f32 = 3.0;
+
+/// Every character belonging to an account, oldest first
+fn roster(ctx: &ReducerContext, owner: spacetimedb::Identity) -> Vec<Entity> {
+    let mut characters: Vec<_> = ctx
+        .db
+        .entity()
+        .iter()
+        .filter(|e| e.entity_type == EntityType::Player && e.identity == Some(owner))
+        .collect();
+    characters.sort_by_key(|c| (c.created_at, c.id));
+    characters
+}
+
+/// Where a new character appears: the chosen region's spawn room, or the
+/// first active region's when none is given
+fn spawn_room(ctx: &ReducerContext, region_id: Option<u64>) -> Result<u64, String> {
+    let region = match region_id {
+        Some(id) => Some(
+            ctx.db
+                .region()
+                .id()
+                .find(id)
+                .filter(|r| r.is_active)
+                .ok_or("No such starting region")?,
+        ),
+        None => ctx
+            .db
+            .region()
+            .iter()
+            .filter(|r| r.is_active)
+            .min_by_key(|r| r.id),
+    };
+
+    let room_id = region.map(|r| r.default_spawn_room).unwrap_or(1);
+    if ctx.db.room().id().find(room_id).is_none() {
+        return Err("That region has nowhere to start".to_string());
+    }
+
+    Ok(room_id)
+}
+
+/// Starting skills and the kit from the starting_skill/starting_kit tables
+fn outfit(ctx: &ReducerContext, character_id: u64) {
+    let now = ctx.timestamp.to_micros_since_unix_epoch();
+
+    for start in ctx.db.starting_skill().iter() {
+        ctx.db.skill().insert(Skill {
+            id: 0,
+            entity_id: character_id,
+            skill_type: start.skill_type,
+            level: start.level,
+            experience: 0,
+            last_used: now,
+            times_used: 0,
+        });
+    }
+
+    for kit in ctx.db.starting_kit().iter() {
+        let Some(template) = ctx.db.item_template().id().find(kit.template_id) else {
+            log::error!(
+                "Starting kit refers to missing template {}",
+                kit.template_id
+            );
+            continue;
+        };
+
+        let item_id =
+            match inventory::give_from_template(ctx, &template, kit.quantity, character_id) {
+                Ok(id) => id,
+                Err(e) => {
+                    log::error!("Failed to give starting item {}: {}", template.name, e);
+                    continue;
+                }
+            };
+
+        let slot = match (template.equip_slot, template.item_type) {
+            (Some(slot), _) => slot,
+            (None, ItemType::Weapon) => EquipSlot::MainHand,
+            _ => continue,
+        };
+
+        // First item listed for a slot wins
+        let occupied = inventory::carried_items(ctx, character_id)
+            .iter()
+            .any(|i| i.is_equipped && i.equipped_slot == Some(slot));
+        if !kit.equip || occupied {
+            continue;
+        }
+
+        if let Some(mut item) = ctx.db.item_data().entity_id().find(item_id) {
+            item.is_equipped = true;
+            item.equipped_slot = Some(slot);
+            ctx.db.item_data().entity_id().update(item);
+        }
+    }
+}
+
+/// A character on the caller's roster
+fn owned_character(
+    ctx: &ReducerContext,
+    account: &Account,
+    character_id: u64,
+) -> Result<Entity, String> {
+    ctx.db
+        .entity()
+        .id()
+        .find(character_id)
+        .filter(|c| c.entity_type == EntityType::Player && c.identity == Some(account.identity))
+        .ok_or_else(|| "You have no such character".to_string())
+}
 
 #[reducer]
 pub fn create_account(
@@ -49,7 +191,7 @@ pub fn create_account(
     };
 
     use base64::{engine::general_purpose, Engine as _};
-    let salt_b64 = general_purpose::STANDARD_NO_PAD.encode(&salt_bytes);
+    let salt_b64 = general_purpose::STANDARD_NO_PAD.encode(salt_bytes);
     let salt =
         SaltString::from_b64(&salt_b64).map_err(|e| format!("Failed to create salt: {}", e))?;
 
@@ -82,52 +224,84 @@ pub fn create_account(
     Ok(())
 }
 
+/// Create a character, rolling its stats unless `stats` gives a point-buy
+/// spread (dexterity, strength, vitality, perception, willpower)
 #[reducer]
-pub fn create_character(ctx: &ReducerContext, name: String) -> Result<(), String> {
+pub fn create_character(
+    ctx: &ReducerContext,
+    name: String,
+    region_id: Option<u64>,
+    stats: Option<Vec<u8>>,
+) -> Result<(), String> {
     log::info!("Creating character: {}", name);
 
-    let account = ctx
-        .db
-        .account()
-        .identity()
-        .find(&ctx.sender)
-        .ok_or("No account found. Create account first.")?;
+    let account = session::caller_account(ctx)?;
+
+    let limit = config::get_f32(ctx, "characters.max_per_account", DEFAULT_MAX_CHARACTERS) as usize;
+    if roster(ctx, account.identity).len() >= limit {
+        return Err(format!(
+            "You already have {} characters; delete one first",
+            limit
+        ));
+    }
+
+    let blocked: Vec<String> = config::get_value(ctx, "names.blocked")
+        .map(|v| v.split(',').map(|w| w.to_string()).collect())
+        .unwrap_or_default();
+    let name = validate_character_name(&name, &blocked)?;
 
     let name_taken = ctx
         .db
         .entity()
         .iter()
-        .any(|e| e.entity_type == EntityType::Player && e.name == name);
+        .any(|e| e.entity_type == EntityType::Player && e.name.eq_ignore_ascii_case(&name));
 
     if name_taken {
         return Err(format!("Character name '{}' already taken", name));
     }
 
+    let room_id = spawn_room(ctx, region_id)?;
+
+    let [dexterity, strength, vitality, perception, willpower] = match stats {
+        Some(stats) => {
+            if config::get_f32(ctx, "characters.point_buy", 1.0) == 0.0 {
+                return Err("Point-buy is disabled; stats will be rolled".to_string());
+            }
+            check_point_buy(&stats)?;
+            [stats[0], stats[1], stats[2], stats[3], stats[4]]
+        }
+        None => {
+            let mut rng = ctx.rng();
+            std::array::from_fn(|_| rolled_stat(rng.gen::<f32>(), rng.gen::<f32>()))
+        }
+    };
+
     let character = Entity {
         id: 0,
-        identity: Some(ctx.sender),
+        identity: Some(account.identity),
         entity_type: EntityType::Player,
         name,
         description: "A brave adventurer.".to_string(),
-        room_id: 1,
+        room_id,
         x: 0.0,
         y: 0.0,
         z: 0.0,
         volume: 70.0,
         weight: 70.0,
         max_capacity: 50.0,
-        hp: 100,
-        max_hp: 100,
+        hp: max_hp(vitality),
+        max_hp: max_hp(vitality),
         stamina: 100,
         max_stamina: 100,
-        mana: 100,
-        max_mana: 100,
-        dexterity: 100,
-        strength: 100,
-        vitality: 100,
-        perception: 100,
-        willpower: 100,
+        mana: max_mana(perception, willpower),
+        max_mana: max_mana(perception, willpower),
+        dexterity,
+        strength,
+        vitality,
+        perception,
+        willpower,
         is_alive: true,
+        position: Position::Standing,
         is_active: true,
         created_at: ctx.timestamp.to_micros_since_unix_epoch(),
         last_action_at: ctx.timestamp.to_micros_since_unix_epoch(),
@@ -139,14 +313,297 @@ pub fn create_character(ctx: &ReducerContext, name: String) -> Result<(), String
         .try_insert(character)
         .map_err(|e| format!("Failed to create character: {:?}", e))?;
 
-    let mut updated_account = account;
-    updated_account.primary_character_id = Some(inserted.id);
-    ctx.db.account().identity().update(updated_account);
+    outfit(ctx, inserted.id);
+
+    log::info!(
+        "{}: DEX {} STR {} VIT {} PER {} WIL {}",
+        inserted.name,
+        dexterity,
+        strength,
+        vitality,
+        perception,
+        willpower
+    );
+
+    // A logged-in player with nothing selected starts playing the new character
+    if let Ok(mut current) = session::current_session(ctx) {
+        if current.character_id == 0 {
+            current.character_id = inserted.id;
+            ctx.db.player_session().identity().update(current);
+        } else if let Some(mut character) = ctx.db.entity().id().find(inserted.id) {
+            character.is_active = false;
+            ctx.db.entity().id().update(character);
+        }
+    }
+
+    if account.primary_character_id.is_none() {
+        let mut updated_account = account;
+        updated_account.primary_character_id = Some(inserted.id);
+        ctx.db.account().identity().update(updated_account);
+    }
 
     log::info!("Character created successfully!");
     Ok(())
 }
 
+#[reducer]
+pub fn list_characters(ctx: &ReducerContext) -> Result<(), String> {
+    let account = session::caller_account(ctx)?;
+    let selected = session::current_session(ctx)
+        .map(|s| s.character_id)
+        .unwrap_or(0);
+    let limit = config::get_f32(ctx, "characters.max_per_account", DEFAULT_MAX_CHARACTERS) as usize;
+    let characters = roster(ctx, account.identity);
+
+    // Use unique markers for parsing
+    log::info!("<<<CHARACTERS_START>>>");
+    log::info!("Characters ({}/{}):", characters.len(), limit);
+
+    if characters.is_empty() {
+        log::info!("  (none)");
+    }
+
+    for character in characters {
+        let marker = if character.id == selected { "*" } else { " " };
+        let state = if character.is_alive { "" } else { " (dead)" };
+        log::info!(
+            "{} [{}] {} - HP {}/{}{}",
+            marker,
+            character.id,
+            character.name,
+            character.hp,
+            character.max_hp,
+            state
+        );
+    }
+
+    log::info!("<<<CHARACTERS_END>>>");
+
+    Ok(())
+}
+
+#[reducer]
+pub fn select_character(ctx: &ReducerContext, character_id: u64) -> Result<(), String> {
+    let mut current = session::current_session(ctx)?;
+    let mut account = session::caller_account(ctx)?;
+    let character = owned_character(ctx, &account, character_id)?;
+
+    if current.character_id == character.id {
+        return Err(format!("You are already playing {}", character.name));
+    }
+
+    // Nobody else may be playing it
+    let in_use = ctx
+        .db
+        .player_session()
+        .iter()
+        .any(|s| s.is_active && s.identity != ctx.sender && s.character_id == character.id);
+    if in_use {
+        return Err(format!("{} is in use by another session", character.name));
+    }
+
+    if let Some(previous) = ctx.db.entity().id().find(current.character_id) {
+        if combat::engagement_of(ctx, previous.id).is_some() {
+            return Err("You can't switch characters in the middle of a fight".to_string());
+        }
+        session::leave_character(ctx, previous);
+    }
+
+    current.character_id = character.id;
+    ctx.db.player_session().identity().update(current);
+
+    let name = character.name.clone();
+    let mut character = character;
+    character.is_active = true;
+    ctx.db.entity().id().update(character);
+
+    // Log in as this character next time
+    account.primary_character_id = Some(character_id);
+    ctx.db.account().identity().update(account);
+
+    log::info!("Now playing {}", name);
+    Ok(())
+}
+
+/// Permanently delete a character; `confirm_name` must repeat its name
+#[reducer]
+pub fn delete_character(
+    ctx: &ReducerContext,
+    character_id: u64,
+    confirm_name: String,
+) -> Result<(), String> {
+    let account = session::caller_account(ctx)?;
+    let character = owned_character(ctx, &account, character_id)?;
+
+    if !character.name.eq_ignore_ascii_case(confirm_name.trim()) {
+        return Err(format!(
+            "To delete {} you must confirm by typing its name",
+            character.name
+        ));
+    }
+
+    let in_use = ctx
+        .db
+        .player_session()
+        .iter()
+        .any(|s| s.is_active && s.identity != ctx.sender && s.character_id == character.id);
+    if in_use {
+        return Err(format!("{} is in use by another session", character.name));
+    }
+
+    let trading = ctx
+        .db
+        .trade_session()
+        .initiator_id()
+        .filter(character.id)
+        .next()
+        .is_some()
+        || ctx
+            .db
+            .trade_session()
+            .partner_id()
+            .filter(character.id)
+            .next()
+            .is_some();
+    if trading {
+        return Err("Cancel the character's open trade first".to_string());
+    }
+
+    // Parcels hold escrowed items and gold that only this character can claim
+    let mail: Vec<_> = ctx.db.mail().recipient_id().filter(character.id).collect();
+    let unclaimed = mail.iter().any(|m| {
+        m.gold > 0
+            || ctx
+                .db
+                .mail_attachment()
+                .mail_id()
+                .filter(m.id)
+                .next()
+                .is_some()
+    });
+    if unclaimed {
+        return Err(format!(
+            "{} has parcels waiting at the post office; claim them first",
+            character.name
+        ));
+    }
+
+    // Deselect it if it's being played
+    if let Ok(mut current) = session::current_session(ctx) {
+        if current.character_id == character.id {
+            current.character_id = 0;
+            ctx.db.player_session().identity().update(current);
+        }
+    }
+
+    // Ends its own fight and every fight aimed at it
+    combat::disengage(ctx, character.id);
+    timing::clear_queue(ctx, character.id);
+    groups::remove_member(ctx, character.id);
+    ctx.db.follower().entity_id().delete(character.id);
+    let followers: Vec<_> = ctx
+        .db
+        .follower()
+        .leader_id()
+        .filter(character.id)
+        .map(|f| f.entity_id)
+        .collect();
+    for follower_id in followers {
+        ctx.db.follower().entity_id().delete(follower_id);
+    }
+
+    // Coins leave the economy through the ledger; everything else just goes
+    let gold = inventory::gold_carried(ctx, character.id).min(u32::MAX as u64) as u32;
+    ledger::debit_gold(
+        ctx,
+        character.id,
+        gold,
+        GoldFlow::Admin,
+        None,
+        "character deleted",
+    )?;
+    for item in inventory::carried_items(ctx, character.id) {
+        inventory::destroy_with_contents(ctx, item.entity_id);
+    }
+
+    let skills: Vec<_> = ctx
+        .db
+        .skill()
+        .entity_id()
+        .filter(character.id)
+        .map(|s| s.id)
+        .collect();
+    for id in skills {
+        ctx.db.skill().id().delete(id);
+    }
+
+    let conditions: Vec<_> = ctx
+        .db
+        .condition()
+        .entity_id()
+        .filter(character.id)
+        .map(|c| c.id)
+        .collect();
+    for id in conditions {
+        ctx.db.condition().id().delete(id);
+    }
+
+    ctx.db.stealth_state().entity_id().delete(character.id);
+    stealth::forget_checks(ctx, character.id);
+    threat::forget_entity(ctx, character.id);
+
+    // Nothing left to track
+    let trails: Vec<_> = ctx
+        .db
+        .trail()
+        .iter()
+        .filter(|t| t.entity_id == character.id)
+        .map(|t| t.id)
+        .collect();
+    for id in trails {
+        ctx.db.trail().id().delete(id);
+    }
+
+    let memberships: Vec<_> = ctx
+        .db
+        .chat_membership()
+        .entity_id()
+        .filter(character.id)
+        .map(|m| m.id)
+        .collect();
+    for id in memberships {
+        ctx.db.chat_membership().id().delete(id);
+    }
+
+    let ignores: Vec<_> = ctx
+        .db
+        .chat_ignore()
+        .entity_id()
+        .filter(character.id)
+        .map(|i| i.id)
+        .collect();
+    for id in ignores {
+        ctx.db.chat_ignore().id().delete(id);
+    }
+
+    for letter in mail {
+        ctx.db.mail().id().delete(letter.id);
+    }
+
+    let name = character.name.clone();
+    ctx.db.entity().id().delete(character.id);
+
+    // Point logins at whatever character is left
+    if account.primary_character_id == Some(character_id) {
+        let mut account = account;
+        account.primary_character_id = roster(ctx, account.identity).first().map(|c| c.id);
+        ctx.db.account().identity().update(account);
+    }
+
+    log::info!("Deleted character {}", name);
+    Ok(())
+}
+
 #[reducer]
 pub fn login(ctx: &ReducerContext, username: String, password: String) -> Result<(), String> {
     log::info!("Login attempt: {}", username);
@@ -174,11 +631,31 @@ pub fn login(ctx: &ReducerContext, username: String, password: String) -> Result
         .verify_password(password.as_bytes(), &parsed_hash)
         .map_err(|_| "Invalid username or password".to_string())?;
 
+    let now = ctx.timestamp.to_micros_since_unix_epoch();
+    let character_id = account.primary_character_id.unwrap_or(0);
+
+    // Replace this connection's old session and anyone else still playing the character
+    let stale: Vec<_> = ctx
+        .db
+        .player_session()
+        .iter()
+        .filter(|s| {
+            s.identity == ctx.sender || (character_id != 0 && s.character_id == character_id)
+        })
+        .collect();
+
+    for old in stale {
+        let identity = old.identity;
+        session::end_session(ctx, old);
+        ctx.db.player_session().identity().delete(identity);
+    }
+
     let session = PlayerSession {
         identity: ctx.sender,
-        character_id: account.primary_character_id.unwrap_or(0),
-        connected_at: ctx.timestamp.to_micros_since_unix_epoch(),
-        last_heartbeat: ctx.timestamp.to_micros_since_unix_epoch(),
+        account: account.identity,
+        character_id,
+        connected_at: now,
+        last_heartbeat: now,
         client_type: ClientType::Unknown,
         client_version: "0.1.0".to_string(),
         is_active: true,
@@ -189,6 +666,84 @@ pub fn login(ctx: &ReducerContext, username: String, password: String) -> Result
         .try_insert(session)
         .map_err(|e| format!("Failed to create session: {:?}", e))?;
 
+    if let Some(mut character) = ctx.db.entity().id().find(character_id) {
+        character.is_active = true;
+        ctx.db.entity().id().update(character);
+    }
+
+    let mut account = account;
+    account.last_login = now;
+    ctx.db.account().identity().update(account);
+
     log::info!("Login successful for: {}", username);
     Ok(())
 }
+
+#[reducer]
+pub fn logout(ctx: &ReducerContext) -> Result<(), String> {
+    let session = ctx
+        .db
+        .player_session()
+        .identity()
+        .find(ctx.sender)
+        .ok_or("Not logged in")?;
+
+    session::end_session(ctx, session);
+    ctx.db.player_session().identity().delete(ctx.sender);
+
+    log::info!("Logged out");
+    Ok(())
+}
+
+/// Clients call this periodically so the session sweep knows they are alive
+#[reducer]
+pub fn heartbeat(ctx: &ReducerContext) -> Result<(), String> {
+    let mut session = ctx
+        .db
+        .player_session()
+        .identity()
+        .find(ctx.sender)
+        .ok_or("Not logged in")?;
+
+    if !session.is_active {
+        return Err("Your session has ended; log in again".to_string());
+    }
+
+    session.last_heartbeat = ctx.timestamp.to_micros_since_unix_epoch();
+    ctx.db.player_session().identity().update(session);
+
+    Ok(())
+}
+
+/// Slow tick: time out sessions whose client has gone quiet and drop
+/// long-closed ones
+#[reducer]
+pub fn sweep_sessions(ctx: &ReducerContext) {
+    let now = ctx.timestamp.to_micros_since_unix_epoch();
+    let timeout = config::get_f32(ctx, "session.timeout_secs", DEFAULT_SESSION_TIMEOUT_SECS) as i64
+        * 1_000_000;
+
+    let sessions: Vec<_> = ctx.db.player_session().iter().collect();
+    let mut timed_out = 0;
+    let mut removed = 0;
+
+    for session in sessions {
+        if session.is_active && now - session.last_heartbeat >= timeout {
+            session::end_session(ctx, session);
+            timed_out += 1;
+        } else if !session.is_active
+            && now - session.last_heartbeat >= CLOSED_SESSION_RETENTION_MICROS
+        {
+            ctx.db.player_session().identity().delete(session.identity);
+            removed += 1;
+        }
+    }
+
+    if timed_out > 0 || removed > 0 {
+        log::info!(
+            "Session sweep: {} timed out, {} closed sessions removed",
+            timed_out,
+            removed
+        );
+    }
+}
@@ -0,0 +1,466 @@
+use crate::tables::{
+    chat::chat_channel,    // ← Add trait import
+    chat::chat_ignore,     // ← Add trait import
+    chat::chat_membership, // ← Add trait import
+    chat::chat_message,    // ← Add trait import
+    chat::chat_silence,    // ← Add trait import
+    entity::entity,        // ← Add trait import
+    ActionType,
+    ChannelKind,
+    ChatChannel,
+    ChatIgnore,
+    ChatMembership,
+    ChatMessage,
+    ChatSilence,
+    Entity,
+};
+use crate::utils::{rate_limits, session};
+use spacetimedb::{reducer, ReducerContext, Table};
+
+/// Longest message accepted on a channel
+const MAX_CHAT_LEN: usize = 400;
+
+/// Most messages shown by read_channel
+const READ_LIMIT: usize = 50;
+
+/// History kept on channels that don't ask for anything else
+const DEFAULT_HISTORY_SECS: u32 = 3_600;
+
+/// Longest history a player-made channel may keep
+const MAX_HISTORY_SECS: u32 = 86_400;
+
+/// Channels every world starts with
+const DEFAULT_CHANNELS: [(&str, ChannelKind); 2] = [
+    ("global", ChannelKind::Public),
+    ("staff", ChannelKind::Admin),
+];
+
+/// Create the built-in channels if they are missing
+pub fn create_default_channels(ctx: &ReducerContext) {
+    for (name, kind) in DEFAULT_CHANNELS {
+        if find_channel(ctx, name).is_some() {
+            continue;
+        }
+
+        ctx.db.chat_channel().insert(ChatChannel {
+            id: 0,
+            name: name.to_string(),
+            kind,
+            owner_id: None,
+            history_secs: DEFAULT_HISTORY_SECS,
+            created_at: ctx.timestamp.to_micros_since_unix_epoch(),
+        });
+    }
+}
+
+fn find_channel(ctx: &ReducerContext, name: &str) -> Option<ChatChannel> {
+    let name = name.trim().to_lowercase();
+    let channel = ctx.db.chat_channel().name().filter(&name).next();
+    channel
+}
+
+fn membership(ctx: &ReducerContext, channel_id: u64, entity_id: u64) -> Option<ChatMembership> {
+    ctx.db
+        .chat_membership()
+        .entity_id()
+        .filter(entity_id)
+        .find(|m| m.channel_id == channel_id)
+}
+
+fn add_member(ctx: &ReducerContext, channel_id: u64, entity_id: u64) {
+    ctx.db.chat_membership().insert(ChatMembership {
+        id: 0,
+        channel_id,
+        entity_id,
+        joined_at: ctx.timestamp.to_micros_since_unix_epoch(),
+    });
+}
+
+/// Staff channels are for admins and moderators only
+fn check_staff(ctx: &ReducerContext, channel: &ChatChannel) -> Result<(), String> {
+    if channel.kind == ChannelKind::Admin {
+        session::require_moderator(ctx).map_err(|_| "That channel is for staff only")?;
+    }
+    Ok(())
+}
+
+fn validate_channel_name(name: &str) -> Result<String, String> {
+    let name = name.trim().to_lowercase();
+
+    if name.len() < 3 || name.len() > 24 {
+        return Err("Channel names must be 3 to 24 characters".to_string());
+    }
+
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err("Channel names may only use letters, digits, - and _".to_string());
+    }
+
+    Ok(name)
+}
+
+#[reducer]
+pub fn list_channels(ctx: &ReducerContext) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+
+    // Use unique markers for parsing
+    log::info!("<<<CHANNELS_START>>>");
+
+    for channel in ctx.db.chat_channel().iter() {
+        if channel.kind == ChannelKind::Admin && check_staff(ctx, &channel).is_err() {
+            continue;
+        }
+
+        let joined = if membership(ctx, channel.id, player.id).is_some() {
+            " (joined)"
+        } else {
+            ""
+        };
+        log::info!("  {} [{:?}]{}", channel.name, channel.kind, joined);
+    }
+
+    log::info!("<<<CHANNELS_END>>>");
+
+    Ok(())
+}
+
+#[reducer]
+pub fn create_channel(
+    ctx: &ReducerContext,
+    name: String,
+    kind: ChannelKind,
+    history_secs: u32,
+) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+    let name = validate_channel_name(&name)?;
+
+    if kind == ChannelKind::Admin {
+        session::require_admin(ctx)?;
+    }
+
+    if find_channel(ctx, &name).is_some() {
+        return Err(format!("Channel '{}' already exists", name));
+    }
+
+    let history_secs = match history_secs {
+        0 => DEFAULT_HISTORY_SECS,
+        secs => secs.min(MAX_HISTORY_SECS),
+    };
+
+    let channel = ctx
+        .db
+        .chat_channel()
+        .try_insert(ChatChannel {
+            id: 0,
+            name,
+            kind,
+            owner_id: Some(player.id),
+            history_secs,
+            created_at: ctx.timestamp.to_micros_since_unix_epoch(),
+        })
+        .map_err(|e| format!("Failed to create channel: {:?}", e))?;
+
+    add_member(ctx, channel.id, player.id);
+
+    log::info!(
+        "{} created {:?} channel '{}'",
+        player.name,
+        channel.kind,
+        channel.name
+    );
+    Ok(())
+}
+
+#[reducer]
+pub fn join_channel(ctx: &ReducerContext, name: String) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+    let channel = find_channel(ctx, &name).ok_or("No such channel")?;
+
+    check_staff(ctx, &channel)?;
+
+    if channel.kind == ChannelKind::Guild && channel.owner_id != Some(player.id) {
+        return Err("You need to be added to that channel".to_string());
+    }
+
+    if membership(ctx, channel.id, player.id).is_some() {
+        return Err(format!("You are already on '{}'", channel.name));
+    }
+
+    add_member(ctx, channel.id, player.id);
+
+    log::info!("{} joined '{}'", player.name, channel.name);
+    Ok(())
+}
+
+#[reducer]
+pub fn leave_channel(ctx: &ReducerContext, name: String) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+    let channel = find_channel(ctx, &name).ok_or("No such channel")?;
+
+    let member = membership(ctx, channel.id, player.id)
+        .ok_or(format!("You are not on '{}'", channel.name))?;
+    ctx.db.chat_membership().id().delete(member.id);
+
+    log::info!("{} left '{}'", player.name, channel.name);
+    Ok(())
+}
+
+/// Guild channel owners bring members in themselves
+#[reducer]
+pub fn channel_add(ctx: &ReducerContext, name: String, entity_id: u64) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+    let channel = find_channel(ctx, &name).ok_or("No such channel")?;
+
+    if channel.owner_id != Some(player.id) {
+        return Err(format!("You don't run '{}'", channel.name));
+    }
+
+    let member = ctx
+        .db
+        .entity()
+        .id()
+        .find(entity_id)
+        .ok_or("Character not found")?;
+
+    if membership(ctx, channel.id, member.id).is_some() {
+        return Err(format!("{} is already on '{}'", member.name, channel.name));
+    }
+
+    add_member(ctx, channel.id, member.id);
+
+    log::info!(
+        "{} added {} to '{}'",
+        player.name,
+        member.name,
+        channel.name
+    );
+    Ok(())
+}
+
+#[reducer]
+pub fn channel_send(ctx: &ReducerContext, name: String, text: String) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+    let channel = find_channel(ctx, &name).ok_or("No such channel")?;
+
+    if membership(ctx, channel.id, player.id).is_none() {
+        return Err(format!("You are not on '{}'", channel.name));
+    }
+
+    check_staff(ctx, &channel)?;
+
+    // Silences follow the account, whichever of its characters is speaking
+    let now = ctx.timestamp.to_micros_since_unix_epoch();
+    let owner = player.identity.unwrap_or(ctx.sender);
+    if let Some(silence) = ctx.db.chat_silence().identity().find(owner) {
+        if silence.until > now {
+            return Err(format!("You have been silenced: {}", silence.reason));
+        }
+        ctx.db.chat_silence().identity().delete(owner);
+    }
+
+    let text = text.trim();
+    if text.is_empty() {
+        return Err("Say what?".to_string());
+    }
+
+    if text.len() > MAX_CHAT_LEN {
+        return Err("That message is too long".to_string());
+    }
+
+    rate_limits::check(ctx, ActionType::Speech)?;
+
+    ctx.db
+        .chat_message()
+        .try_insert(ChatMessage {
+            id: 0,
+            channel_id: channel.id,
+            sender_id: player.id,
+            sender_name: player.name.clone(),
+            text: text.to_string(),
+            sent_at: now,
+            expires_at: now + channel.history_secs as i64 * 1_000_000,
+        })
+        .map_err(|e| format!("Failed to send message: {:?}", e))?;
+
+    // The text itself is only shown through read_channel, which honours ignores
+    log::info!("{} posted on [{}]", player.name, channel.name);
+    Ok(())
+}
+
+/// Show a channel's recent messages, leaving out anyone you ignore
+#[reducer]
+pub fn read_channel(ctx: &ReducerContext, name: String) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+    let channel = find_channel(ctx, &name).ok_or("No such channel")?;
+
+    if membership(ctx, channel.id, player.id).is_none() {
+        return Err(format!("You are not on '{}'", channel.name));
+    }
+
+    check_staff(ctx, &channel)?;
+
+    let ignored: Vec<u64> = ctx
+        .db
+        .chat_ignore()
+        .entity_id()
+        .filter(player.id)
+        .map(|i| i.ignored_id)
+        .collect();
+
+    let mut messages: Vec<_> = ctx
+        .db
+        .chat_message()
+        .channel_id()
+        .filter(channel.id)
+        .filter(|m| !ignored.contains(&m.sender_id))
+        .collect();
+    messages.sort_by_key(|m| (m.sent_at, m.id));
+    let skip = messages.len().saturating_sub(READ_LIMIT);
+
+    // Use unique markers for parsing
+    log::info!("<<<CHAT_START>>>");
+    log::info!("[{}]", channel.name);
+
+    if messages.is_empty() {
+        log::info!("  (no messages)");
+    }
+
+    for message in messages.into_iter().skip(skip) {
+        log::info!("  {}: {}", message.sender_name, message.text);
+    }
+
+    log::info!("<<<CHAT_END>>>");
+
+    Ok(())
+}
+
+/// Hide another character's channel messages from yourself
+#[reducer]
+pub fn ignore(ctx: &ReducerContext, entity_id: u64) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+    let target = find_character(ctx, entity_id)?;
+
+    if target.id == player.id {
+        return Err("You can't ignore yourself".to_string());
+    }
+
+    if ctx
+        .db
+        .chat_ignore()
+        .entity_id()
+        .filter(player.id)
+        .any(|i| i.ignored_id == target.id)
+    {
+        return Err(format!("You are already ignoring {}", target.name));
+    }
+
+    ctx.db.chat_ignore().insert(ChatIgnore {
+        id: 0,
+        entity_id: player.id,
+        ignored_id: target.id,
+    });
+
+    log::info!("{} now ignores {}", player.name, target.name);
+    Ok(())
+}
+
+#[reducer]
+pub fn unignore(ctx: &ReducerContext, entity_id: u64) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+
+    let entry = ctx
+        .db
+        .chat_ignore()
+        .entity_id()
+        .filter(player.id)
+        .find(|i| i.ignored_id == entity_id)
+        .ok_or("You are not ignoring them")?;
+    ctx.db.chat_ignore().id().delete(entry.id);
+
+    log::info!("{} stopped ignoring {}", player.name, entity_id);
+    Ok(())
+}
+
+/// Moderator: keep a player's account off every channel for a while
+#[reducer]
+pub fn silence(
+    ctx: &ReducerContext,
+    entity_id: u64,
+    minutes: u32,
+    reason: String,
+) -> Result<(), String> {
+    let moderator = session::require_moderator(ctx)?;
+    let target = find_character(ctx, entity_id)?;
+    let identity = target.identity.ok_or("Only players can be silenced")?;
+
+    if minutes == 0 {
+        return Err("Silence for how long?".to_string());
+    }
+
+    let silence = ChatSilence {
+        identity,
+        until: ctx.timestamp.to_micros_since_unix_epoch() + minutes as i64 * 60_000_000,
+        moderator: moderator.identity,
+        reason: reason.trim().to_string(),
+    };
+
+    if ctx.db.chat_silence().identity().find(identity).is_some() {
+        ctx.db.chat_silence().identity().update(silence);
+    } else {
+        ctx.db.chat_silence().insert(silence);
+    }
+
+    log::info!(
+        "{} silenced {} for {} minutes",
+        moderator.username,
+        target.name,
+        minutes
+    );
+    Ok(())
+}
+
+#[reducer]
+pub fn unsilence(ctx: &ReducerContext, entity_id: u64) -> Result<(), String> {
+    let moderator = session::require_moderator(ctx)?;
+    let target = find_character(ctx, entity_id)?;
+    let identity = target.identity.ok_or("Only players can be silenced")?;
+
+    if !ctx.db.chat_silence().identity().delete(identity) {
+        return Err(format!("{} is not silenced", target.name));
+    }
+
+    log::info!("{} lifted {}'s silence", moderator.username, target.name);
+    Ok(())
+}
+
+fn find_character(ctx: &ReducerContext, entity_id: u64) -> Result<Entity, String> {
+    ctx.db
+        .entity()
+        .id()
+        .find(entity_id)
+        .ok_or_else(|| "Character not found".to_string())
+}
+
+/// Slow tick: drop channel messages past their channel's retention
+#[reducer]
+pub fn expire_chat_history(ctx: &ReducerContext) {
+    let now = ctx.timestamp.to_micros_since_unix_epoch();
+
+    let expired: Vec<_> = ctx
+        .db
+        .chat_message()
+        .expires_at()
+        .filter(..now)
+        .map(|m| m.id)
+        .collect();
+
+    for id in &expired {
+        ctx.db.chat_message().id().delete(*id);
+    }
+
+    if !expired.is_empty() {
+        log::debug!("Expired {} chat messages", expired.len());
+    }
+}
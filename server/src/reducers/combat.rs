@@ -1,126 +1,159 @@
+use crate::reducers::perform_move;
 use crate::tables::{
-    entity::entity,                 // ← Add trait import
-    game_event::game_event,         // ← Add trait import
-    player_session::player_session, // ← Add trait import
-    room::room,                     // ← Add trait import
+    engagement::engagement, // ← Add trait import
+    entity::entity,         // ← Add trait import
+    game_event::game_event, // ← Add trait import
+    room::room,             // ← Add trait import
+    ActionKind,
+    ActionType,
+    ConditionType,
+    Entity,
     EventType,
     GameEvent,
+    HitLocation,
+    QueuedAction,
+    TickKind,
 };
-use crate::utils::combat_stats;
-use spacetimedb::{reducer, ReducerContext, Table};
+use crate::utils::{combat, conditions, rate_limits, rooms, session, ticks, timing};
+use dogmud_common::flee_chance;
+use spacetimedb::{rand::Rng, reducer, ReducerContext, Table};
+
+const FLEE_STAMINA_COST: i32 = 15;
 
 #[reducer]
 pub fn attack(ctx: &ReducerContext, target_id: u64) -> Result<(), String> {
     log::info!("Attack initiated against target {}", target_id);
+    start_attack(ctx, target_id, None)
+}
 
-    let session = ctx
-        .db
-        .player_session()
-        .identity()
-        .find(&ctx.sender)
-        .ok_or("Not logged in")?;
+/// Attack aimed at one body part, trading accuracy for where it lands
+#[reducer]
+pub fn aimed_attack(
+    ctx: &ReducerContext,
+    target_id: u64,
+    location: HitLocation,
+) -> Result<(), String> {
+    log::info!("Aimed attack at {:?} of target {}", location, target_id);
+    start_attack(ctx, target_id, Some(location))
+}
 
-    if session.character_id == 0 {
-        return Err("No character selected".to_string());
-    }
+fn start_attack(
+    ctx: &ReducerContext,
+    target_id: u64,
+    aim: Option<HitLocation>,
+) -> Result<(), String> {
+    let attacker = session::current_character(ctx)?;
+    rate_limits::check(ctx, ActionType::Attack)?;
 
-    let mut attacker = ctx
+    let target = ctx
         .db
         .entity()
         .id()
-        .find(&session.character_id)
-        .ok_or("Attacker not found")?;
+        .find(target_id)
+        .ok_or("Target not found")?;
+
+    combat::check_melee(ctx, &attacker, &target)?;
+
+    let action = QueuedAction {
+        target_id: Some(target_id),
+        location: aim,
+        ..timing::action(ActionKind::Attack)
+    };
+
+    if timing::ready_or_queue(ctx, &attacker, action)? {
+        perform_attack(ctx, attacker, target_id, aim)?;
+    }
+
+    Ok(())
+}
 
-    let mut target = ctx
+/// Everything that can stop an attack, checked before anything is written
+pub fn check_attack(
+    ctx: &ReducerContext,
+    attacker: &Entity,
+    target_id: u64,
+) -> Result<Entity, String> {
+    let target = ctx
         .db
         .entity()
         .id()
-        .find(&target_id)
+        .find(target_id)
         .ok_or("Target not found")?;
 
-    if !attacker.is_alive {
-        return Err("You are dead".to_string());
+    combat::check_melee(ctx, attacker, &target)?;
+
+    // Already fighting: swings come on the combat tick, not as fast as you can type
+    if let Some(current) = combat::engagement_of(ctx, attacker.id) {
+        if current.target_id == target_id {
+            return Err(format!("You are already fighting {}", target.name));
+        }
     }
 
-    if !target.is_alive {
-        return Err("Target is already dead".to_string());
+    Ok(target)
+}
+
+/// Engage a target and take the opening swing; also run from the action queue
+pub fn perform_attack(
+    ctx: &ReducerContext,
+    attacker: Entity,
+    target_id: u64,
+    aim: Option<HitLocation>,
+) -> Result<(), String> {
+    let target = check_attack(ctx, &attacker, target_id)?;
+
+    let target_engaged = combat::engagement_of(ctx, target.id).is_some();
+    combat::engage(ctx, &attacker, target.id);
+
+    // The target fights back unless it is busy with someone else
+    if !target_engaged {
+        combat::engage(ctx, &target, attacker.id);
     }
 
-    if attacker.id == target.id {
-        return Err("You cannot attack yourself".to_string());
+    combat::resolve_melee(ctx, attacker, target, aim)?;
+
+    Ok(())
+}
+
+/// Try to break off every fight and run through a random exit
+#[reducer]
+pub fn flee(ctx: &ReducerContext) -> Result<(), String> {
+    let mut player = session::current_character(ctx)?;
+
+    let opponents = combat::opponents_here(ctx, &player);
+
+    if opponents.is_empty() {
+        return Err("You are not fighting anyone".to_string());
     }
 
-    if attacker.room_id != target.room_id {
-        return Err("Target is not in the same room".to_string());
+    if player.stamina < FLEE_STAMINA_COST {
+        return Err("You are too exhausted to flee".to_string());
     }
 
     let room = ctx
         .db
         .room()
         .id()
-        .find(&attacker.room_id)
+        .find(player.room_id)
         .ok_or("Room not found")?;
 
-    if !room.allows_combat {
-        return Err("Combat is not allowed here".to_string());
-    }
-
-    if attacker.stamina < 10 {
-        return Err("Not enough stamina to attack".to_string());
-    }
+    let exits: Vec<_> = ["north", "south", "east", "west", "up", "down"]
+        .into_iter()
+        .filter(|d| rooms::exit_toward(&room, d).ok().flatten().is_some())
+        .collect();
 
-    let attack_stat = ((attacker.dexterity as u16 + attacker.strength as u16) / 2) as u8;
-    let attack_skill = 50u8;
-    let attack_roll = combat_stats::calculate_roll_base(attack_stat, attack_skill, 1.0);
-    let attack_sample = combat_stats::random_variance(attack_roll, ctx);
-
-    let defense_stat = ((target.dexterity as u16 + target.perception as u16) / 2) as u8;
-    let defense_skill = 50u8;
-    let defense_roll = combat_stats::calculate_roll_base(defense_stat, defense_skill, 0.9);
-    let defense_sample = combat_stats::random_variance(defense_roll, ctx);
-
-    let hit = attack_sample > defense_sample;
-    let is_crit = combat_stats::is_critical_hit(attack_sample, defense_sample);
-    let is_fumble = combat_stats::is_critical_fail(attack_sample, defense_sample);
-
-    let mut damage = 0;
-    let mut result_message = String::new();
-
-    if is_fumble {
-        result_message = format!("{} fumbles the attack!", attacker.name);
-    } else if !hit {
-        result_message = format!("{} misses {}", attacker.name, target.name);
-    } else {
-        let base_damage = (attacker.strength as f32 / 10.0) as i32;
-        damage = base_damage;
-
-        if is_crit {
-            damage = (damage as f32 * 1.3) as i32;
-            result_message = format!(
-                "{} critically hits {} for {} damage!",
-                attacker.name, target.name, damage
-            );
-        } else {
-            result_message = format!(
-                "{} hits {} for {} damage",
-                attacker.name, target.name, damage
-            );
-        }
-
-        target.hp = (target.hp - damage).max(0);
-
-        if target.hp == 0 {
-            target.is_alive = false;
-            result_message.push_str(&format!(" {} has died!", target.name));
-        }
+    if exits.is_empty() {
+        return Err("There is nowhere to run!".to_string());
     }
 
-    attacker.stamina = (attacker.stamina - 10).max(0);
-    attacker.last_action_at = ctx.timestamp.to_micros_since_unix_epoch();
+    // The quickest opponent decides whether you get away
+    let fastest = opponents.iter().map(|e| e.dexterity).max().unwrap_or(0);
+    let escaped = ctx.rng().gen::<f32>() < flee_chance(player.dexterity, fastest);
 
-    let attacker_id = attacker.id;
-    ctx.db.entity().id().update(attacker);
-    ctx.db.entity().id().update(target);
+    player.stamina -= FLEE_STAMINA_COST;
+    let player_id = player.id;
+    let player_name = player.name.clone();
+    ctx.db.entity().id().update(player);
+    timing::clear_queue(ctx, player_id);
 
     let event = GameEvent {
         id: 0,
@@ -128,11 +161,11 @@ pub fn attack(ctx: &ReducerContext, target_id: u64) -> Result<(), String> {
         timestamp: ctx.timestamp.to_micros_since_unix_epoch(),
         event_type: EventType::Combat,
         event_data: format!(
-            "{{\"attacker\": {}, \"target\": {}, \"damage\": {}, \"hit\": {}, \"critical\": {}}}",
-            attacker_id, target_id, damage, hit, is_crit
+            "{{\"action\": \"flee\", \"entity_id\": {}, \"escaped\": {}}}",
+            player_id, escaped
         ),
-        primary_actor: attacker_id,
-        secondary_actor: Some(target_id),
+        primary_actor: player_id,
+        secondary_actor: None,
         requires_sight: true,
         requires_hearing: true,
         stealth_dc: None,
@@ -144,7 +177,83 @@ pub fn attack(ctx: &ReducerContext, target_id: u64) -> Result<(), String> {
         .try_insert(event)
         .map_err(|e| format!("Failed to create event: {:?}", e))?;
 
-    log::info!("Combat: {}", result_message);
+    if !escaped {
+        log::info!("{} tries to flee but can't get away!", player_name);
+        return Ok(());
+    }
 
-    Ok(())
+    let direction = exits[ctx.rng().gen_range(0..exits.len())];
+    log::info!("{} flees {}!", player_name, direction);
+
+    // Getting away breaks off the fight; running is not held up by the step timer
+    combat::disengage(ctx, player_id);
+    let player = ctx
+        .db
+        .entity()
+        .id()
+        .find(player_id)
+        .ok_or("Character not found")?;
+    perform_move(ctx, player, direction)
+}
+
+/// Fast tick: resolve the automatic swings that are due in a region
+#[reducer]
+pub fn tick_combat(ctx: &ReducerContext, region_id: u64) {
+    if !ticks::claim(ctx, region_id, TickKind::Combat) {
+        return;
+    }
+
+    let now = ctx.timestamp.to_micros_since_unix_epoch();
+
+    for room in ctx.db.room().region_id().filter(region_id) {
+        let due: Vec<_> = ctx
+            .db
+            .engagement()
+            .room_id()
+            .filter(room.id)
+            .filter(|e| e.next_attack_at <= now)
+            .collect();
+
+        for engagement in due {
+            // An earlier swing this tick may have ended this fight
+            if combat::engagement_of(ctx, engagement.entity_id).is_none() {
+                continue;
+            }
+
+            let attacker = ctx.db.entity().id().find(engagement.entity_id);
+            let target = ctx.db.entity().id().find(engagement.target_id);
+
+            let (Some(attacker), Some(target)) = (attacker, target) else {
+                ctx.db.engagement().entity_id().delete(engagement.entity_id);
+                continue;
+            };
+
+            let winded = attacker.stamina < combat::ATTACK_STAMINA_COST;
+            let stunned = conditions::has_condition(ctx, attacker.id, ConditionType::Stunned);
+            if attacker.is_alive && (winded || stunned) {
+                // Catch your breath and try again next interval
+                let mut engagement = engagement;
+                engagement.next_attack_at = now + combat::attack_interval_micros(ctx, &attacker);
+                ctx.db.engagement().entity_id().update(engagement);
+                continue;
+            }
+
+            if combat::check_melee(ctx, &attacker, &target).is_err() {
+                ctx.db.engagement().entity_id().delete(engagement.entity_id);
+                continue;
+            }
+
+            let interval = combat::attack_interval_micros(ctx, &attacker);
+            if let Err(e) = combat::resolve_melee(ctx, attacker, target, None) {
+                log::error!("Auto-attack failed: {}", e);
+            }
+
+            // Wait out the interval even after a failed swing, so one error
+            // isn't repeated on every tick
+            if let Some(mut engagement) = combat::engagement_of(ctx, engagement.entity_id) {
+                engagement.next_attack_at = now + interval;
+                ctx.db.engagement().entity_id().update(engagement);
+            }
+        }
+    }
 }
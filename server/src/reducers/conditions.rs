@@ -0,0 +1,106 @@
+use crate::tables::{
+    condition::condition,   // ← Add trait import
+    entity::entity,         // ← Add trait import
+    game_event::game_event, // ← Add trait import
+    room::room,             // ← Add trait import
+    ConditionType,
+    EventType,
+    GameEvent,
+    TickKind,
+};
+use crate::utils::{combat, threat, ticks};
+use spacetimedb::{reducer, ReducerContext, Table};
+
+/// HP change per tick for each point of magnitude
+fn hp_per_tick(condition_type: ConditionType, magnitude: f32) -> i32 {
+    match condition_type {
+        ConditionType::Poisoned | ConditionType::Burning | ConditionType::Bleeding => {
+            -(magnitude.round() as i32)
+        }
+        ConditionType::Regenerating => magnitude.round() as i32,
+        _ => 0,
+    }
+}
+
+/// Fast tick: apply damage/healing over time and expire conditions in a region
+#[reducer]
+pub fn tick_conditions(ctx: &ReducerContext, region_id: u64) {
+    if !ticks::claim(ctx, region_id, TickKind::Conditions) {
+        return;
+    }
+
+    let now = ctx.timestamp.to_micros_since_unix_epoch();
+    let mut expired = 0;
+
+    for room in ctx.db.room().region_id().filter(region_id) {
+        let entities: Vec<_> = ctx.db.entity().room_id().filter(room.id).collect();
+
+        for mut entity in entities {
+            let conditions: Vec<_> = ctx.db.condition().entity_id().filter(entity.id).collect();
+            if conditions.is_empty() {
+                continue;
+            }
+
+            let was_alive = entity.is_alive;
+            let mut hp_changed = false;
+
+            for mut condition in conditions {
+                let delta = hp_per_tick(condition.condition_type, condition.magnitude);
+                if delta != 0 && entity.is_alive {
+                    let before = entity.hp;
+                    entity.hp = (entity.hp + delta).clamp(0, entity.max_hp);
+                    if let Some(source_id) = condition.source_id {
+                        threat::add_healing_threat(ctx, source_id, entity.id, entity.hp - before);
+                    }
+                    if entity.hp == 0 {
+                        entity.is_alive = false;
+                        combat::disengage(ctx, entity.id);
+                    }
+                    hp_changed = true;
+                }
+
+                if condition.remaining_ticks <= 1 {
+                    let condition_type = condition.condition_type;
+                    ctx.db.condition().id().delete(condition.id);
+                    expired += 1;
+
+                    let event = GameEvent {
+                        id: 0,
+                        room_id: entity.room_id,
+                        timestamp: now,
+                        event_type: EventType::ConditionChange,
+                        event_data: format!(
+                            "{{\"entity_id\": {}, \"condition\": \"{:?}\", \"gained\": false}}",
+                            entity.id, condition_type
+                        ),
+                        primary_actor: entity.id,
+                        secondary_actor: None,
+                        requires_sight: true,
+                        requires_hearing: false,
+                        stealth_dc: None,
+                        expires_at: now + 60_000_000,
+                    };
+
+                    if let Err(e) = ctx.db.game_event().try_insert(event) {
+                        log::error!("Failed to create condition event: {:?}", e);
+                    }
+                } else {
+                    condition.remaining_ticks -= 1;
+                    ctx.db.condition().id().update(condition);
+                }
+            }
+
+            if was_alive && !entity.is_alive {
+                log::info!("{} succumbs to their afflictions", entity.name);
+            }
+
+            if hp_changed {
+                ctx.db.entity().id().update(entity);
+            }
+        }
+    }
+
+    if expired > 0 {
+        log::debug!("Expired {} conditions in region {}", expired, region_id);
+    }
+}
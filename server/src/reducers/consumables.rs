@@ -0,0 +1,213 @@
+use crate::tables::{
+    consumable::consumable_cooldown, // ← Add trait import
+    consumable::consumable_effect,   // ← Add trait import
+    entity::entity,                  // ← Add trait import
+    game_event::game_event,          // ← Add trait import
+    item_data::item_data,            // ← Add trait import
+    ConsumableCooldown,
+    ConsumeMethod,
+    EventType,
+    GameEvent,
+};
+use crate::utils::{conditions, inventory, position, session, threat};
+use spacetimedb::{reducer, ReducerContext, Table};
+
+#[reducer]
+pub fn eat(ctx: &ReducerContext, item_id: u64) -> Result<(), String> {
+    consume(ctx, item_id, ConsumeMethod::Eat)
+}
+
+#[reducer]
+pub fn drink(ctx: &ReducerContext, item_id: u64) -> Result<(), String> {
+    consume(ctx, item_id, ConsumeMethod::Drink)
+}
+
+#[reducer]
+pub fn use_item(ctx: &ReducerContext, item_id: u64) -> Result<(), String> {
+    consume(ctx, item_id, ConsumeMethod::Use)
+}
+
+fn consume(ctx: &ReducerContext, item_id: u64, method: ConsumeMethod) -> Result<(), String> {
+    let mut player = session::current_character(ctx)?;
+
+    if !player.is_alive {
+        return Err("You are dead".to_string());
+    }
+
+    position::require_awake(&player)?;
+
+    if !inventory::is_carried_by(ctx, item_id, player.id) {
+        return Err("You are not carrying that".to_string());
+    }
+
+    let mut item = ctx
+        .db
+        .item_data()
+        .entity_id()
+        .find(item_id)
+        .ok_or("Item not found")?;
+
+    let verb = match method {
+        ConsumeMethod::Eat => "eat",
+        ConsumeMethod::Drink => "drink",
+        ConsumeMethod::Use => "use",
+    };
+
+    let template_id = item.template_id.ok_or(format!("You can't {} that", verb))?;
+
+    let effects: Vec<_> = ctx
+        .db
+        .consumable_effect()
+        .template_id()
+        .filter(template_id)
+        .filter(|e| e.method == method)
+        .collect();
+
+    if effects.is_empty() {
+        return Err(format!("You can't {} that", verb));
+    }
+
+    let now = ctx.timestamp.to_micros_since_unix_epoch();
+    let cooldown = ctx
+        .db
+        .consumable_cooldown()
+        .entity_id()
+        .filter(player.id)
+        .find(|c| c.template_id == template_id);
+
+    if let Some(cooldown) = &cooldown {
+        if cooldown.ready_at > now {
+            let wait = (cooldown.ready_at - now + 999_999) / 1_000_000;
+            return Err(format!(
+                "You must wait {}s before you {} another",
+                wait, verb
+            ));
+        }
+    }
+
+    // One unit off the stack
+    if item.quantity > 1 {
+        item.quantity -= 1;
+        ctx.db.item_data().entity_id().update(item);
+    } else {
+        inventory::destroy_item(ctx, item_id);
+    }
+
+    let mut cooldown_secs = 0;
+    for effect in &effects {
+        let healed = (player.hp + effect.heal_hp).clamp(0, player.max_hp) - player.hp;
+        player.hp += healed;
+        threat::add_healing_threat(ctx, player.id, player.id, healed);
+        player.stamina = (player.stamina + effect.heal_stamina).clamp(0, player.max_stamina);
+        player.mana = (player.mana + effect.heal_mana).clamp(0, player.max_mana);
+
+        if let Some(cured) = effect.cure_condition {
+            if conditions::remove_condition(ctx, player.id, cured) {
+                condition_event(
+                    ctx,
+                    player.room_id,
+                    player.id,
+                    &format!("{:?}", cured),
+                    false,
+                );
+            }
+        }
+
+        if let Some(applied) = effect.apply_condition {
+            conditions::apply_condition(
+                ctx,
+                player.id,
+                applied,
+                effect.magnitude,
+                effect.duration_ticks,
+                Some(player.id),
+            );
+            condition_event(
+                ctx,
+                player.room_id,
+                player.id,
+                &format!("{:?}", applied),
+                true,
+            );
+        }
+
+        cooldown_secs = cooldown_secs.max(effect.cooldown_secs);
+    }
+
+    if cooldown_secs > 0 {
+        let ready_at = now + cooldown_secs as i64 * 1_000_000;
+        match cooldown {
+            Some(mut cooldown) => {
+                cooldown.ready_at = ready_at;
+                ctx.db.consumable_cooldown().id().update(cooldown);
+            }
+            None => {
+                ctx.db.consumable_cooldown().insert(ConsumableCooldown {
+                    id: 0,
+                    entity_id: player.id,
+                    template_id,
+                    ready_at,
+                });
+            }
+        }
+    }
+
+    let player_id = player.id;
+    let room_id = player.room_id;
+    let player_name = player.name.clone();
+    ctx.db.entity().id().update(player);
+
+    let event = GameEvent {
+        id: 0,
+        room_id,
+        timestamp: now,
+        event_type: EventType::ItemInteraction,
+        event_data: format!(
+            "{{\"action\": \"{}\", \"item\": {}, \"template\": {}}}",
+            verb, item_id, template_id
+        ),
+        primary_actor: player_id,
+        secondary_actor: None,
+        requires_sight: true,
+        requires_hearing: false,
+        stealth_dc: None,
+        expires_at: now + 60_000_000,
+    };
+
+    ctx.db
+        .game_event()
+        .try_insert(event)
+        .map_err(|e| format!("Failed to create event: {:?}", e))?;
+
+    log::info!("{} used item {} ({})", player_name, item_id, verb);
+    Ok(())
+}
+
+fn condition_event(
+    ctx: &ReducerContext,
+    room_id: u64,
+    entity_id: u64,
+    condition: &str,
+    gained: bool,
+) {
+    let event = GameEvent {
+        id: 0,
+        room_id,
+        timestamp: ctx.timestamp.to_micros_since_unix_epoch(),
+        event_type: EventType::ConditionChange,
+        event_data: format!(
+            "{{\"entity_id\": {}, \"condition\": \"{}\", \"gained\": {}}}",
+            entity_id, condition, gained
+        ),
+        primary_actor: entity_id,
+        secondary_actor: None,
+        requires_sight: true,
+        requires_hearing: false,
+        stealth_dc: None,
+        expires_at: ctx.timestamp.to_micros_since_unix_epoch() + 60_000_000,
+    };
+
+    if let Err(e) = ctx.db.game_event().try_insert(event) {
+        log::error!("Failed to create condition event: {:?}", e);
+    }
+}
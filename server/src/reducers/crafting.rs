@@ -0,0 +1,224 @@
+use crate::tables::{
+    entity::entity,               // ← Add trait import
+    game_event::game_event,       // ← Add trait import
+    item_template::item_template, // ← Add trait import
+    recipe::recipe,               // ← Add trait import
+    recipe::recipe_component,     // ← Add trait import
+    Entity,
+    EntityType,
+    EventType,
+    GameEvent,
+    SkillType,
+};
+use crate::utils::{combat_stats, inventory, position, session, skills};
+use dogmud_common::{apply_quality, craft_quality};
+use spacetimedb::{reducer, ReducerContext, Table};
+
+/// The stat that drives each crafting skill
+fn crafting_stat(entity: &Entity, skill_type: SkillType) -> u8 {
+    match skill_type {
+        SkillType::Blacksmithing => entity.strength,
+        SkillType::Hidemaking | SkillType::Bowyery => entity.dexterity,
+        SkillType::Alchemy | SkillType::Cooking => entity.perception,
+        _ => entity.dexterity,
+    }
+}
+
+#[reducer]
+pub fn list_recipes(ctx: &ReducerContext) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+
+    // Use unique markers for parsing
+    log::info!("<<<RECIPES_START>>>");
+
+    for recipe in ctx.db.recipe().iter() {
+        let skill = skills::skill_level(ctx, player.id, recipe.skill_type);
+        let station = recipe
+            .station
+            .as_deref()
+            .map(|s| format!(" at a {}", s))
+            .unwrap_or_default();
+        log::info!(
+            "  [{}] {} ({:?} {}, difficulty {}){}",
+            recipe.id,
+            recipe.name,
+            recipe.skill_type,
+            skill,
+            recipe.difficulty,
+            station
+        );
+
+        for component in ctx.db.recipe_component().recipe_id().filter(recipe.id) {
+            let Some(template) = ctx.db.item_template().id().find(component.template_id) else {
+                continue;
+            };
+            let have = inventory::count_from_template(ctx, player.id, template.id);
+            if component.is_tool {
+                log::info!("      tool: {} ({})", template.name, have.min(1));
+            } else {
+                log::info!("      {} x{} ({})", template.name, component.quantity, have);
+            }
+        }
+    }
+
+    log::info!("<<<RECIPES_END>>>");
+
+    Ok(())
+}
+
+#[reducer]
+pub fn craft(ctx: &ReducerContext, recipe_id: u64) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+
+    if !player.is_alive {
+        return Err("You are dead".to_string());
+    }
+
+    position::require_awake(&player)?;
+
+    let recipe = ctx
+        .db
+        .recipe()
+        .id()
+        .find(recipe_id)
+        .ok_or("Unknown recipe")?;
+
+    if let Some(station) = &recipe.station {
+        let has_station =
+            ctx.db.entity().room_id().filter(player.room_id).any(|e| {
+                e.entity_type == EntityType::Fixture && e.name.eq_ignore_ascii_case(station)
+            });
+
+        if !has_station {
+            return Err(format!("You need a {} to make that", station));
+        }
+    }
+
+    let components: Vec<_> = ctx
+        .db
+        .recipe_component()
+        .recipe_id()
+        .filter(recipe.id)
+        .collect();
+
+    for component in &components {
+        let needed = if component.is_tool {
+            1
+        } else {
+            component.quantity
+        };
+
+        if inventory::count_from_template(ctx, player.id, component.template_id) < needed {
+            let name = ctx
+                .db
+                .item_template()
+                .id()
+                .find(component.template_id)
+                .map(|t| t.name)
+                .unwrap_or_else(|| "something".to_string());
+            return Err(format!("You are missing {}", name));
+        }
+    }
+
+    let template = ctx
+        .db
+        .item_template()
+        .id()
+        .find(recipe.output_template_id)
+        .ok_or("Recipe output not found")?;
+
+    // Materials are used up whether or not the attempt succeeds
+    for component in components.iter().filter(|c| !c.is_tool) {
+        inventory::consume_from_template(
+            ctx,
+            player.id,
+            component.template_id,
+            component.quantity,
+        )?;
+    }
+
+    let skill = skills::skill_level(ctx, player.id, recipe.skill_type);
+    let stat = crafting_stat(&player, recipe.skill_type);
+    let roll = combat_stats::calculate_roll_base(stat, skill, 1.0);
+    let sample = combat_stats::random_variance(roll, ctx);
+    let success = sample >= recipe.difficulty as f32;
+
+    let mut item_id = None;
+    let mut quality = 0.0;
+
+    if success {
+        quality = craft_quality(sample, recipe.difficulty);
+
+        if template.max_stack > 1 {
+            // Stackable goods (food, potions) have no quality to vary
+            item_id = Some(inventory::give_from_template(
+                ctx,
+                &template,
+                recipe.output_quantity,
+                player.id,
+            )?);
+        } else {
+            let mut crafted = template;
+            crafted.base_value = (crafted.base_value as f32 * quality).round() as u32;
+            crafted.base_damage = apply_quality(crafted.base_damage, quality);
+            crafted.armor_rating = apply_quality(crafted.armor_rating, quality);
+            crafted.max_durability = apply_quality(crafted.max_durability, quality);
+
+            for _ in 0..recipe.output_quantity.max(1) {
+                let id = inventory::spawn_item(ctx, &crafted, 1, 0)?;
+                inventory::give_item(ctx, id, player.id)?;
+                item_id = Some(id);
+            }
+        }
+    }
+
+    let new_level =
+        skills::practice_skill(ctx, player.id, recipe.skill_type, recipe.difficulty as u32);
+
+    let event = GameEvent {
+        id: 0,
+        room_id: player.room_id,
+        timestamp: ctx.timestamp.to_micros_since_unix_epoch(),
+        event_type: EventType::ItemInteraction,
+        event_data: format!(
+            "{{\"action\": \"craft\", \"recipe\": {}, \"success\": {}, \"item\": {}, \"quality\": {:.2}}}",
+            recipe.id,
+            success,
+            item_id.map_or("null".to_string(), |id| id.to_string()),
+            quality
+        ),
+        primary_actor: player.id,
+        secondary_actor: None,
+        requires_sight: true,
+        requires_hearing: false,
+        stealth_dc: None,
+        expires_at: ctx.timestamp.to_micros_since_unix_epoch() + 60_000_000,
+    };
+
+    ctx.db
+        .game_event()
+        .try_insert(event)
+        .map_err(|e| format!("Failed to create event: {:?}", e))?;
+
+    if success {
+        log::info!(
+            "{} crafted {} (quality {:.2})",
+            player.name,
+            recipe.name,
+            quality
+        );
+    } else {
+        log::info!("{} failed to craft {}", player.name, recipe.name);
+    }
+
+    if let Some(level) = new_level {
+        log::info!(
+            "{}'s {:?} improves to {}",
+            player.name,
+            recipe.skill_type,
+            level
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,322 @@
+use crate::tables::{
+    entity::entity,               // ← Add trait import
+    game_event::game_event,       // ← Add trait import
+    item_data::item_data,         // ← Add trait import
+    item_template::item_template, // ← Add trait import
+    EntityType,
+    EquipSlot,
+    EventType,
+    GameEvent,
+    GoldFlow,
+    ItemType,
+    NPCRole,
+    SkillType,
+};
+use crate::utils::{groups, inventory, ledger, npc, position, session, skills};
+use dogmud_common::{repair_cost, repair_wear_amount, repair_wear_chance};
+use spacetimedb::{rand::Rng, reducer, ReducerContext, Table};
+
+/// Skill assumed for blacksmith NPCs that have no Blacksmithing row
+const NPC_BLACKSMITH_SKILL: u8 = 60;
+
+/// Blacksmithing needed to repair your own gear without a smith
+const SELF_REPAIR_MIN_SKILL: u8 = 20;
+
+fn slots_conflict(a: EquipSlot, b: EquipSlot) -> bool {
+    a == b
+        || matches!(
+            (a, b),
+            (EquipSlot::TwoHand, EquipSlot::MainHand)
+                | (EquipSlot::TwoHand, EquipSlot::OffHand)
+                | (EquipSlot::MainHand, EquipSlot::TwoHand)
+                | (EquipSlot::OffHand, EquipSlot::TwoHand)
+        )
+}
+
+#[reducer]
+pub fn list_inventory(ctx: &ReducerContext) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+
+    // Use unique markers for parsing
+    log::info!("<<<INVENTORY_START>>>");
+    log::info!("You are carrying:");
+
+    let items = inventory::carried_items(ctx, player.id);
+    if items.is_empty() {
+        log::info!("  Nothing.");
+    }
+
+    for item in items {
+        let Some(entity) = ctx.db.entity().id().find(item.entity_id) else {
+            continue;
+        };
+
+        let mut line = format!("  {} (ID: {})", entity.name, entity.id);
+        if item.quantity > 1 {
+            line.push_str(&format!(" x{}", item.quantity));
+        }
+        if item.max_durability > 0 {
+            line.push_str(&format!(" [{}/{}]", item.durability, item.max_durability));
+        }
+        if inventory::is_broken(&item) {
+            line.push_str(" (broken)");
+        }
+        if let Some(slot) = item.equipped_slot {
+            line.push_str(&format!(" <{:?}>", slot));
+        }
+        log::info!("{}", line);
+    }
+
+    log::info!("<<<INVENTORY_END>>>");
+
+    Ok(())
+}
+
+/// Pick up an item lying here. In a group, gold is divided and items go to
+/// whoever the loot rule picks
+#[reducer]
+pub fn pick_up(ctx: &ReducerContext, item_id: u64) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+
+    if !player.is_alive {
+        return Err("You are dead".to_string());
+    }
+
+    position::require_awake(&player)?;
+
+    let entity = ctx
+        .db
+        .entity()
+        .id()
+        .find(item_id)
+        .filter(|e| e.entity_type == EntityType::Item && e.room_id == player.room_id)
+        .ok_or("That isn't here")?;
+
+    let item = ctx
+        .db
+        .item_data()
+        .entity_id()
+        .find(item_id)
+        .ok_or("Item not found")?;
+
+    let event_data = if item.item_type == ItemType::Gold {
+        let amount = item.quantity;
+        inventory::destroy_item(ctx, item_id);
+
+        let shares = groups::gold_shares(ctx, &player, amount);
+        let reason = format!("picked up in room {}", player.room_id);
+        for (entity_id, share) in &shares {
+            ledger::credit_gold(ctx, *entity_id, *share, GoldFlow::Loot, None, &reason)?;
+        }
+
+        format!(
+            "{{\"action\": \"pick_up\", \"item\": {}, \"gold\": {}, \"shared_by\": {}}}",
+            item_id,
+            amount,
+            shares.len()
+        )
+    } else {
+        let looter_id = groups::looter(ctx, &player);
+        inventory::give_item(ctx, item_id, looter_id)?;
+
+        format!(
+            "{{\"action\": \"pick_up\", \"item\": {}, \"looter\": {}}}",
+            item_id, looter_id
+        )
+    };
+
+    let event = GameEvent {
+        id: 0,
+        room_id: player.room_id,
+        timestamp: ctx.timestamp.to_micros_since_unix_epoch(),
+        event_type: EventType::ItemInteraction,
+        event_data,
+        primary_actor: player.id,
+        secondary_actor: None,
+        requires_sight: true,
+        requires_hearing: false,
+        stealth_dc: None,
+        expires_at: ctx.timestamp.to_micros_since_unix_epoch() + 60_000_000,
+    };
+
+    ctx.db
+        .game_event()
+        .try_insert(event)
+        .map_err(|e| format!("Failed to create event: {:?}", e))?;
+
+    log::info!("{} picks up {} (ID: {})", player.name, entity.name, item_id);
+    Ok(())
+}
+
+#[reducer]
+pub fn equip(ctx: &ReducerContext, item_id: u64) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+
+    if !inventory::is_carried_by(ctx, item_id, player.id) {
+        return Err("You are not carrying that".to_string());
+    }
+
+    let mut item = ctx
+        .db
+        .item_data()
+        .entity_id()
+        .find(item_id)
+        .ok_or("Item not found")?;
+
+    if item.is_equipped {
+        return Err("You already have that equipped".to_string());
+    }
+
+    let template_slot = item
+        .template_id
+        .and_then(|id| ctx.db.item_template().id().find(id))
+        .and_then(|t| t.equip_slot);
+
+    let slot = match (template_slot, item.item_type) {
+        (Some(slot), _) => slot,
+        (None, ItemType::Weapon) => EquipSlot::MainHand,
+        _ => return Err("You can't equip that".to_string()),
+    };
+
+    // Swap out whatever occupies the slot
+    for mut other in inventory::carried_items(ctx, player.id) {
+        if other.is_equipped && other.equipped_slot.is_some_and(|s| slots_conflict(s, slot)) {
+            other.is_equipped = false;
+            other.equipped_slot = None;
+            ctx.db.item_data().entity_id().update(other);
+        }
+    }
+
+    item.is_equipped = true;
+    item.equipped_slot = Some(slot);
+    ctx.db.item_data().entity_id().update(item);
+
+    log::info!("{} equipped item {} ({:?})", player.name, item_id, slot);
+    Ok(())
+}
+
+#[reducer]
+pub fn unequip(ctx: &ReducerContext, item_id: u64) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+
+    if !inventory::is_carried_by(ctx, item_id, player.id) {
+        return Err("You are not carrying that".to_string());
+    }
+
+    let mut item = ctx
+        .db
+        .item_data()
+        .entity_id()
+        .find(item_id)
+        .ok_or("Item not found")?;
+
+    if !item.is_equipped {
+        return Err("You don't have that equipped".to_string());
+    }
+
+    item.is_equipped = false;
+    item.equipped_slot = None;
+    ctx.db.item_data().entity_id().update(item);
+
+    log::info!("{} unequipped item {}", player.name, item_id);
+    Ok(())
+}
+
+/// Restore an item's durability at a blacksmith, or yourself with enough skill
+#[reducer]
+pub fn repair(ctx: &ReducerContext, item_id: u64) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+
+    if !player.is_alive {
+        return Err("You are dead".to_string());
+    }
+
+    if !inventory::is_carried_by(ctx, item_id, player.id) {
+        return Err("You are not carrying that".to_string());
+    }
+
+    let mut item = ctx
+        .db
+        .item_data()
+        .entity_id()
+        .find(item_id)
+        .ok_or("Item not found")?;
+
+    if item.max_durability == 0 {
+        return Err("That can't be repaired".to_string());
+    }
+
+    if item.durability >= item.max_durability {
+        return Err("That doesn't need repairing".to_string());
+    }
+
+    let cost = repair_cost(item.base_value, item.durability, item.max_durability);
+
+    let (smith_skill, cost, smith_id) =
+        match npc::find_npc_with_role(ctx, player.room_id, NPCRole::Blacksmith) {
+            Some(smith) => {
+                let skill = skills::skill_level(ctx, smith.id, SkillType::Blacksmithing);
+                (skill.max(NPC_BLACKSMITH_SKILL), cost, Some(smith.id))
+            }
+            None => {
+                let skill = skills::skill_level(ctx, player.id, SkillType::Blacksmithing);
+                if skill < SELF_REPAIR_MIN_SKILL {
+                    return Err("There is no blacksmith here".to_string());
+                }
+                // Doing it yourself only costs the materials
+                (skill, (cost / 2).max(1), None)
+            }
+        };
+
+    ledger::debit_gold(
+        ctx,
+        player.id,
+        cost,
+        GoldFlow::Repair,
+        smith_id,
+        &format!("repaired item {}", item_id),
+    )?;
+
+    let worn = ctx.rng().gen::<f32>() < repair_wear_chance(smith_skill);
+    if worn {
+        item.max_durability = item
+            .max_durability
+            .saturating_sub(repair_wear_amount(item.max_durability))
+            .max(1);
+    }
+    item.durability = item.max_durability;
+    let max_durability = item.max_durability;
+    ctx.db.item_data().entity_id().update(item);
+
+    let event = GameEvent {
+        id: 0,
+        room_id: player.room_id,
+        timestamp: ctx.timestamp.to_micros_since_unix_epoch(),
+        event_type: EventType::ItemInteraction,
+        event_data: format!(
+            "{{\"action\": \"repair\", \"item\": {}, \"cost\": {}, \"max_durability\": {}, \"worn\": {}}}",
+            item_id, cost, max_durability, worn
+        ),
+        primary_actor: player.id,
+        secondary_actor: smith_id,
+        requires_sight: true,
+        requires_hearing: false,
+        stealth_dc: None,
+        expires_at: ctx.timestamp.to_micros_since_unix_epoch() + 60_000_000,
+    };
+
+    ctx.db
+        .game_event()
+        .try_insert(event)
+        .map_err(|e| format!("Failed to create event: {:?}", e))?;
+
+    log::info!(
+        "{} had item {} repaired for {} gold (max durability now {})",
+        player.name,
+        item_id,
+        cost,
+        max_durability
+    );
+
+    Ok(())
+}
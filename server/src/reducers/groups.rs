@@ -0,0 +1,408 @@
+use crate::tables::{
+    entity::entity,          // ← Add trait import
+    game_event::game_event,  // ← Add trait import
+    group::follower,         // ← Add trait import
+    group::group,            // ← Add trait import
+    group::group_invitation, // ← Add trait import
+    ActionType,
+    EntityType,
+    EventType,
+    Follower,
+    GameEvent,
+    GoldFlow,
+    GroupInvitation,
+    LootRule,
+};
+use crate::utils::{groups, inventory, ledger, rate_limits, session};
+use spacetimedb::{reducer, ReducerContext, Table};
+
+/// Longest message accepted on the group channel
+const MAX_GROUP_MESSAGE_LEN: usize = 256;
+
+fn group_event(
+    ctx: &ReducerContext,
+    room_id: u64,
+    event_type: EventType,
+    event_data: String,
+    actor_id: u64,
+    other_id: Option<u64>,
+) {
+    let event = GameEvent {
+        id: 0,
+        room_id,
+        timestamp: ctx.timestamp.to_micros_since_unix_epoch(),
+        event_type,
+        event_data,
+        primary_actor: actor_id,
+        secondary_actor: other_id,
+        requires_sight: false,
+        requires_hearing: false,
+        stealth_dc: None,
+        expires_at: ctx.timestamp.to_micros_since_unix_epoch() + 60_000_000,
+    };
+
+    if let Err(e) = ctx.db.game_event().try_insert(event) {
+        log::error!("Failed to create group event: {:?}", e);
+    }
+}
+
+/// Invite a player in the same room; a group is founded when they accept
+#[reducer]
+pub fn group_invite(ctx: &ReducerContext, target_id: u64) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+
+    if !player.is_alive {
+        return Err("You are dead".to_string());
+    }
+
+    let target = ctx
+        .db
+        .entity()
+        .id()
+        .find(target_id)
+        .ok_or("Target not found")?;
+
+    if target.id == player.id {
+        return Err("You are already in your own company".to_string());
+    }
+
+    if target.entity_type != EntityType::Player || !target.is_alive {
+        return Err(format!("{} cannot join a group", target.name));
+    }
+
+    if target.room_id != player.room_id {
+        return Err(format!("{} is not here", target.name));
+    }
+
+    if groups::group_of(ctx, target.id).is_some() {
+        return Err(format!("{} is already in a group", target.name));
+    }
+
+    let group_id = match groups::group_of(ctx, player.id) {
+        Some(group) if group.leader_id != player.id => {
+            return Err("Only the group leader can invite".to_string());
+        }
+        Some(group) if groups::members(ctx, group.id).len() >= groups::MAX_GROUP_SIZE => {
+            return Err("Your group is full".to_string());
+        }
+        Some(group) => group.id,
+        None => 0,
+    };
+
+    // A newer invitation replaces any older one
+    ctx.db.group_invitation().invitee_id().delete(target.id);
+    ctx.db.group_invitation().insert(GroupInvitation {
+        invitee_id: target.id,
+        group_id,
+        inviter_id: player.id,
+        created_at: ctx.timestamp.to_micros_since_unix_epoch(),
+    });
+
+    group_event(
+        ctx,
+        player.room_id,
+        EventType::Emote,
+        format!(
+            "{{\"action\": \"group_invite\", \"group\": {}, \"invitee\": {}}}",
+            group_id, target.id
+        ),
+        player.id,
+        Some(target.id),
+    );
+
+    log::info!("{} invites {} to their group", player.name, target.name);
+    Ok(())
+}
+
+#[reducer]
+pub fn group_accept(ctx: &ReducerContext) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+
+    let invitation = ctx
+        .db
+        .group_invitation()
+        .invitee_id()
+        .find(player.id)
+        .ok_or("Nobody has invited you to a group")?;
+
+    ctx.db.group_invitation().invitee_id().delete(player.id);
+
+    if groups::group_of(ctx, player.id).is_some() {
+        return Err("You are already in a group".to_string());
+    }
+
+    let group = match ctx.db.group().id().find(invitation.group_id) {
+        Some(group) => group,
+        None if invitation.group_id != 0 => return Err("That group has disbanded".to_string()),
+        None => {
+            if ctx.db.entity().id().find(invitation.inviter_id).is_none() {
+                return Err("Whoever invited you is gone".to_string());
+            }
+            groups::found(ctx, invitation.inviter_id)?
+        }
+    };
+
+    if groups::members(ctx, group.id).len() >= groups::MAX_GROUP_SIZE {
+        return Err("That group is full".to_string());
+    }
+
+    groups::add_member(ctx, group.id, player.id);
+    groups::follow_leader(ctx, player.id, group.leader_id);
+
+    group_event(
+        ctx,
+        player.room_id,
+        EventType::Emote,
+        format!(
+            "{{\"action\": \"group_join\", \"group\": {}, \"entity_id\": {}}}",
+            group.id, player.id
+        ),
+        player.id,
+        Some(group.leader_id),
+    );
+
+    log::info!("{} joins group {}", player.name, group.id);
+    Ok(())
+}
+
+#[reducer]
+pub fn group_leave(ctx: &ReducerContext) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+    let group = groups::group_of(ctx, player.id).ok_or("You are not in a group")?;
+
+    groups::remove_member(ctx, player.id);
+
+    group_event(
+        ctx,
+        player.room_id,
+        EventType::Emote,
+        format!(
+            "{{\"action\": \"group_leave\", \"group\": {}, \"entity_id\": {}}}",
+            group.id, player.id
+        ),
+        player.id,
+        None,
+    );
+
+    log::info!("{} leaves group {}", player.name, group.id);
+    Ok(())
+}
+
+/// Leader: set who keeps picked-up items and whether found gold is shared
+#[reducer]
+pub fn group_rules(
+    ctx: &ReducerContext,
+    loot_rule: LootRule,
+    share_gold: bool,
+) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+    let mut group = groups::group_of(ctx, player.id).ok_or("You are not in a group")?;
+
+    if group.leader_id != player.id {
+        return Err("Only the group leader can set the rules".to_string());
+    }
+
+    group.loot_rule = loot_rule;
+    group.share_gold = share_gold;
+    group.loot_turn = 0;
+    let group_id = group.id;
+    ctx.db.group().id().update(group);
+
+    group_event(
+        ctx,
+        player.room_id,
+        EventType::Emote,
+        format!(
+            "{{\"action\": \"group_rules\", \"group\": {}, \"loot_rule\": \"{:?}\", \"share_gold\": {}}}",
+            group_id, loot_rule, share_gold
+        ),
+        player.id,
+        None,
+    );
+
+    log::info!(
+        "{} sets group {} loot to {:?}, gold {}",
+        player.name,
+        group_id,
+        loot_rule,
+        if share_gold {
+            "shared"
+        } else {
+            "kept by the finder"
+        }
+    );
+    Ok(())
+}
+
+/// Talk to the whole group, wherever its members are
+#[reducer]
+pub fn group_say(ctx: &ReducerContext, text: String) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+    let group = groups::group_of(ctx, player.id).ok_or("You are not in a group")?;
+    rate_limits::check(ctx, ActionType::Speech)?;
+
+    let text = text.trim();
+    if text.is_empty() {
+        return Err("Say what?".to_string());
+    }
+
+    if text.len() > MAX_GROUP_MESSAGE_LEN {
+        return Err("That message is too long".to_string());
+    }
+
+    let mut rooms: Vec<_> = groups::members(ctx, group.id)
+        .into_iter()
+        .filter_map(|m| ctx.db.entity().id().find(m.entity_id))
+        .map(|e| e.room_id)
+        .collect();
+    rooms.sort_unstable();
+    rooms.dedup();
+
+    let event_data = format!(
+        "{{\"channel\": \"group\", \"group\": {}, \"speaker\": {}, \"text\": {}}}",
+        group.id,
+        player.id,
+        serde_json::to_string(text).map_err(|e| e.to_string())?
+    );
+
+    for room_id in rooms {
+        group_event(
+            ctx,
+            room_id,
+            EventType::Speech,
+            event_data.clone(),
+            player.id,
+            None,
+        );
+    }
+
+    log::info!("[group {}] {}: {}", group.id, player.name, text);
+    Ok(())
+}
+
+/// Share carried gold evenly with the group members standing here;
+/// whatever doesn't divide evenly stays with you
+#[reducer]
+pub fn split_gold(ctx: &ReducerContext, amount: u32) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+    let group = groups::group_of(ctx, player.id).ok_or("You are not in a group")?;
+
+    if amount == 0 {
+        return Err("Split how much?".to_string());
+    }
+
+    let carried = inventory::gold_carried(ctx, player.id);
+    if carried < amount as u64 {
+        return Err(format!("You only have {} gold", carried));
+    }
+
+    let others: Vec<_> = groups::members(ctx, group.id)
+        .into_iter()
+        .filter(|m| m.entity_id != player.id)
+        .filter_map(|m| ctx.db.entity().id().find(m.entity_id))
+        .filter(|e| e.is_alive && e.room_id == player.room_id)
+        .map(|e| e.id)
+        .collect();
+
+    if others.is_empty() {
+        return Err("Nobody from your group is here".to_string());
+    }
+
+    let share = amount / (others.len() as u32 + 1);
+    if share == 0 {
+        return Err("That isn't enough to go around".to_string());
+    }
+
+    let reason = format!("group {} split", group.id);
+    for other_id in &others {
+        ledger::transfer_gold(ctx, player.id, *other_id, share, GoldFlow::Group, &reason)?;
+    }
+
+    group_event(
+        ctx,
+        player.room_id,
+        EventType::Economy,
+        format!(
+            "{{\"action\": \"split_gold\", \"group\": {}, \"amount\": {}, \"share\": {}}}",
+            group.id, amount, share
+        ),
+        player.id,
+        None,
+    );
+
+    log::info!(
+        "{} splits {} gold, {} each for {} members",
+        player.name,
+        amount,
+        share,
+        others.len() + 1
+    );
+    Ok(())
+}
+
+/// Trail behind another entity, stepping wherever they step
+#[reducer]
+pub fn follow(ctx: &ReducerContext, target_id: u64) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+
+    if !player.is_alive {
+        return Err("You are dead".to_string());
+    }
+
+    let target = ctx
+        .db
+        .entity()
+        .id()
+        .find(target_id)
+        .ok_or("Target not found")?;
+
+    if target.id == player.id {
+        return Err("You can't follow yourself".to_string());
+    }
+
+    if !target.is_alive || target.room_id != player.room_id {
+        return Err(format!("{} is not here", target.name));
+    }
+
+    // Walk up the target's chain so nobody ends up following in a circle
+    let mut leader_id = target.id;
+    while let Some(next) = ctx.db.follower().entity_id().find(leader_id) {
+        if next.leader_id == player.id {
+            return Err(format!("{} is already following you", target.name));
+        }
+        leader_id = next.leader_id;
+    }
+
+    ctx.db.follower().entity_id().delete(player.id);
+    ctx.db.follower().insert(Follower {
+        entity_id: player.id,
+        leader_id: target.id,
+    });
+
+    group_event(
+        ctx,
+        player.room_id,
+        EventType::Emote,
+        format!(
+            "{{\"action\": \"follow\", \"entity_id\": {}, \"leader\": {}}}",
+            player.id, target.id
+        ),
+        player.id,
+        Some(target.id),
+    );
+
+    log::info!("{} starts following {}", player.name, target.name);
+    Ok(())
+}
+
+#[reducer]
+pub fn unfollow(ctx: &ReducerContext) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+
+    if !ctx.db.follower().entity_id().delete(player.id) {
+        return Err("You are not following anyone".to_string());
+    }
+
+    log::info!("{} stops following", player.name);
+    Ok(())
+}
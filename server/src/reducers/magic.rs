@@ -0,0 +1,297 @@
+use crate::tables::{
+    entity::entity,         // ← Add trait import
+    game_event::game_event, // ← Add trait import
+    room::room,             // ← Add trait import
+    spell::spell,           // ← Add trait import
+    ActionKind,
+    ActionType,
+    Entity,
+    EventType,
+    GameEvent,
+    QueuedAction,
+    SkillType,
+    Spell,
+    SpellTarget,
+};
+use crate::utils::{
+    combat, combat_stats, conditions, groups, inventory, position, rate_limits, resistances,
+    session, skills, stealth, threat, timing,
+};
+use dogmud_common::{apply_resistance, calculate_base_damage};
+use spacetimedb::{reducer, ReducerContext, Table};
+
+#[reducer]
+pub fn list_spells(ctx: &ReducerContext) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+    let skill = skills::skill_level(ctx, player.id, SkillType::MagicCasting);
+
+    // Use unique markers for parsing
+    log::info!("<<<SPELLS_START>>>");
+    log::info!(
+        "MagicCasting {}, mana {}/{}",
+        skill,
+        player.mana,
+        player.max_mana
+    );
+
+    for spell in ctx.db.spell().iter() {
+        log::info!(
+            "  [{}] {} - {} mana, {:?}, {:.1}s: {}",
+            spell.id,
+            spell.name,
+            spell.mana_cost,
+            spell.target_kind,
+            spell.cast_time_ms as f32 / 1000.0,
+            spell.description
+        );
+    }
+
+    log::info!("<<<SPELLS_END>>>");
+
+    Ok(())
+}
+
+#[reducer]
+pub fn cast(ctx: &ReducerContext, spell_id: u64, target_id: Option<u64>) -> Result<(), String> {
+    let caster = session::current_character(ctx)?;
+    rate_limits::check(ctx, ActionType::Attack)?;
+
+    if ctx.db.spell().id().find(spell_id).is_none() {
+        return Err("You don't know that spell".to_string());
+    }
+
+    let action = QueuedAction {
+        spell_id: Some(spell_id),
+        target_id,
+        ..timing::action(ActionKind::Cast)
+    };
+
+    if timing::ready_or_queue(ctx, &caster, action)? {
+        perform_cast(ctx, caster, spell_id, target_id)?;
+    }
+
+    Ok(())
+}
+
+/// Everything that can stop a cast, checked before anything is written.
+/// Returns the spell and who it lands on
+pub fn check_cast(
+    ctx: &ReducerContext,
+    caster: &Entity,
+    spell_id: u64,
+    target_id: Option<u64>,
+) -> Result<(Spell, Vec<u64>), String> {
+    if !caster.is_alive {
+        return Err("You are dead".to_string());
+    }
+
+    position::require_awake(caster)?;
+
+    let spell = ctx
+        .db
+        .spell()
+        .id()
+        .find(spell_id)
+        .ok_or("You don't know that spell")?;
+
+    let room = ctx
+        .db
+        .room()
+        .id()
+        .find(caster.room_id)
+        .ok_or("Room not found")?;
+
+    if !room.allows_magic {
+        return Err("The magic fizzles out here".to_string());
+    }
+
+    if is_harmful(&spell) && !room.allows_combat {
+        return Err("Combat is not allowed here".to_string());
+    }
+
+    if caster.mana < spell.mana_cost {
+        return Err("Not enough mana".to_string());
+    }
+
+    let targets: Vec<u64> = match spell.target_kind {
+        SpellTarget::Caster => vec![caster.id],
+        SpellTarget::Single => {
+            let target_id = target_id.ok_or("Cast it at whom?")?;
+            let target = ctx
+                .db
+                .entity()
+                .id()
+                .find(target_id)
+                .ok_or("Target not found")?;
+
+            if target.id == caster.id {
+                return Err("You cannot target yourself with that".to_string());
+            }
+            if !target.is_alive {
+                return Err("Target is already dead".to_string());
+            }
+            if target.room_id != caster.room_id || !stealth::can_see(ctx, caster, &target) {
+                return Err(format!("{} is not here", target.name));
+            }
+            vec![target.id]
+        }
+        SpellTarget::Room => ctx
+            .db
+            .entity()
+            .room_id()
+            .filter(caster.room_id)
+            .filter(|e| e.id != caster.id && e.is_alive && e.max_hp > 0)
+            .map(|e| e.id)
+            .collect(),
+    };
+
+    Ok((spell, targets))
+}
+
+/// Spend the mana and resolve a spell; also run from the action queue
+pub fn perform_cast(
+    ctx: &ReducerContext,
+    mut caster: Entity,
+    spell_id: u64,
+    target_id: Option<u64>,
+) -> Result<(), String> {
+    let (spell, targets) = check_cast(ctx, &caster, spell_id, target_id)?;
+    let harmful = is_harmful(&spell);
+    let now = ctx.timestamp.to_micros_since_unix_epoch();
+
+    let skill = skills::skill_level(ctx, caster.id, SkillType::MagicCasting);
+    let magic_stat = ((caster.perception as u16 + caster.willpower as u16) / 2) as u8;
+    let cast_roll = combat_stats::calculate_roll_base(magic_stat, skill, 1.0);
+
+    if harmful {
+        stealth::reveal(ctx, &caster);
+    }
+
+    caster.mana -= spell.mana_cost;
+    caster.last_action_at = now;
+    let caster_id = caster.id;
+    let caster_name = caster.name.clone();
+    ctx.db.entity().id().update(caster);
+
+    for target_id in targets {
+        resolve_spell(
+            ctx, &spell, caster_id, magic_stat, skill, cast_roll, target_id,
+        )?;
+    }
+
+    if let Some(level) = skills::practice_skill(
+        ctx,
+        caster_id,
+        SkillType::MagicCasting,
+        spell.mana_cost as u32,
+    ) {
+        log::info!("{}'s MagicCasting improves to {}", caster_name, level);
+    }
+
+    log::info!("{} casts {}", caster_name, spell.name);
+    Ok(())
+}
+
+/// Damage or a hostile condition aimed at someone else: an attack in every
+/// sense, whether or not it deals damage
+fn is_harmful(spell: &Spell) -> bool {
+    spell.target_kind != SpellTarget::Caster
+        && (spell.base_damage > 0 || spell.apply_condition.is_some_and(conditions::is_hostile))
+}
+
+/// Roll one target's willpower against the cast and apply the effects
+fn resolve_spell(
+    ctx: &ReducerContext,
+    spell: &Spell,
+    caster_id: u64,
+    magic_stat: u8,
+    skill: u8,
+    cast_roll: f32,
+    target_id: u64,
+) -> Result<(), String> {
+    let mut target = ctx
+        .db
+        .entity()
+        .id()
+        .find(target_id)
+        .ok_or("Target not found")?;
+
+    // The caster never resists their own spells
+    let resisted = if target.id == caster_id {
+        false
+    } else {
+        let cast_sample = combat_stats::random_variance(cast_roll, ctx);
+        let resist_roll = combat_stats::calculate_roll_base(target.willpower, 0, 0.9);
+        let resist_sample = combat_stats::random_variance(resist_roll, ctx);
+        cast_sample <= resist_sample
+    };
+
+    if is_harmful(spell) && target.id != caster_id {
+        position::wake(&mut target);
+    }
+
+    let mut damage = 0;
+    if !resisted {
+        if spell.base_damage > 0 {
+            let base_damage = calculate_base_damage(spell.base_damage, magic_stat, skill);
+            let armor = inventory::worn_armor(ctx, target.id);
+            let multiplier =
+                resistances::damage_multiplier(ctx, target.id, spell.damage_type, &armor);
+            damage = apply_resistance(base_damage, multiplier) as i32;
+            target.hp = (target.hp - damage).max(0);
+            threat::add_threat(ctx, target.id, caster_id, damage as f32);
+            if target.hp == 0 {
+                target.is_alive = false;
+                combat::disengage(ctx, target.id);
+                log::info!("{} has died!", target.name);
+            }
+        }
+
+        if let Some(condition_type) = spell.apply_condition {
+            conditions::apply_condition(
+                ctx,
+                target.id,
+                condition_type,
+                spell.magnitude,
+                spell.duration_ticks,
+                Some(caster_id),
+            );
+        }
+    }
+
+    let room_id = target.room_id;
+    let killed = damage > 0 && !target.is_alive;
+    ctx.db.entity().id().update(target);
+
+    if killed {
+        groups::share_kill(ctx, caster_id, target_id);
+    }
+
+    let event = GameEvent {
+        id: 0,
+        room_id,
+        timestamp: ctx.timestamp.to_micros_since_unix_epoch(),
+        event_type: if spell.base_damage > 0 {
+            EventType::Combat
+        } else {
+            EventType::ConditionChange
+        },
+        event_data: format!(
+            "{{\"action\": \"cast\", \"spell\": {}, \"caster\": {}, \"target\": {}, \"damage\": {}, \"damage_type\": \"{:?}\", \"resisted\": {}}}",
+            spell.id, caster_id, target_id, damage, spell.damage_type, resisted
+        ),
+        primary_actor: caster_id,
+        secondary_actor: Some(target_id),
+        requires_sight: true,
+        requires_hearing: true,
+        stealth_dc: None,
+        expires_at: ctx.timestamp.to_micros_since_unix_epoch() + 60_000_000,
+    };
+
+    ctx.db
+        .game_event()
+        .try_insert(event)
+        .map_err(|e| format!("Failed to create event: {:?}", e))?;
+
+    Ok(())
+}
@@ -0,0 +1,403 @@
+use crate::tables::{
+    entity::entity,         // ← Add trait import
+    game_event::game_event, // ← Add trait import
+    item_data::item_data,   // ← Add trait import
+    mail::mail,             // ← Add trait import
+    mail::mail_attachment,  // ← Add trait import
+    Entity,
+    EntityType,
+    EventType,
+    GameEvent,
+    GoldFlow,
+    ItemType,
+    Mail,
+    MailAttachment,
+    NPCRole,
+};
+use crate::utils::{inventory, ledger, npc, session};
+use spacetimedb::{reducer, ReducerContext, Table};
+
+/// Letters waiting in one inbox before the postmaster stops accepting more
+const MAX_INBOX: usize = 50;
+
+const MAX_SUBJECT_LEN: usize = 80;
+const MAX_BODY_LEN: usize = 2_000;
+
+/// Items that fit in one parcel
+const MAX_ATTACHMENTS: usize = 8;
+
+fn find_postmaster(ctx: &ReducerContext, room_id: u64) -> Option<Entity> {
+    npc::find_npc_with_role(ctx, room_id, NPCRole::Postmaster)
+}
+
+fn find_recipient(ctx: &ReducerContext, name: &str) -> Result<Entity, String> {
+    let name = name.trim();
+    ctx.db
+        .entity()
+        .entity_type()
+        .filter(EntityType::Player)
+        .find(|e| e.name.eq_ignore_ascii_case(name))
+        .ok_or_else(|| format!("Nobody named '{}' receives mail here", name))
+}
+
+fn own_mail(ctx: &ReducerContext, player: &Entity, mail_id: u64) -> Result<Mail, String> {
+    ctx.db
+        .mail()
+        .id()
+        .find(mail_id)
+        .filter(|m| m.recipient_id == player.id)
+        .ok_or_else(|| "You have no such letter".to_string())
+}
+
+fn attachments(ctx: &ReducerContext, mail_id: u64) -> Vec<MailAttachment> {
+    ctx.db.mail_attachment().mail_id().filter(mail_id).collect()
+}
+
+/// Write a letter, leaving escrow (items and gold) to the caller
+fn post(
+    ctx: &ReducerContext,
+    sender: &Entity,
+    recipient_name: &str,
+    subject: &str,
+    body: &str,
+    gold: u32,
+) -> Result<Mail, String> {
+    let recipient = find_recipient(ctx, recipient_name)?;
+
+    let subject = subject.trim();
+    if subject.is_empty() || subject.len() > MAX_SUBJECT_LEN {
+        return Err(format!(
+            "A subject needs 1 to {} characters",
+            MAX_SUBJECT_LEN
+        ));
+    }
+
+    if body.len() > MAX_BODY_LEN {
+        return Err("That letter is too long".to_string());
+    }
+
+    if ctx.db.mail().recipient_id().filter(recipient.id).count() >= MAX_INBOX {
+        return Err(format!("{}'s mailbox is full", recipient.name));
+    }
+
+    ctx.db
+        .mail()
+        .try_insert(Mail {
+            id: 0,
+            recipient_id: recipient.id,
+            sender_id: sender.id,
+            sender_name: sender.name.clone(),
+            subject: subject.to_string(),
+            body: body.trim().to_string(),
+            sent_at: ctx.timestamp.to_micros_since_unix_epoch(),
+            is_read: false,
+            gold,
+        })
+        .map_err(|e| format!("Failed to send mail: {:?}", e))
+}
+
+/// Send a letter from anywhere
+#[reducer]
+pub fn send_mail(
+    ctx: &ReducerContext,
+    recipient_name: String,
+    subject: String,
+    body: String,
+) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+    let mail = post(ctx, &player, &recipient_name, &subject, &body, 0)?;
+
+    log::info!(
+        "{} sent mail {} to {}",
+        player.name,
+        mail.id,
+        recipient_name
+    );
+    Ok(())
+}
+
+/// Hand items and gold to a postmaster, who holds them for the recipient
+#[reducer]
+pub fn send_parcel(
+    ctx: &ReducerContext,
+    recipient_name: String,
+    subject: String,
+    body: String,
+    item_ids: Vec<u64>,
+    gold: u32,
+) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+
+    if !player.is_alive {
+        return Err("You are dead".to_string());
+    }
+
+    let postmaster = find_postmaster(ctx, player.room_id).ok_or("There is no postmaster here")?;
+
+    if item_ids.is_empty() && gold == 0 {
+        return Err("There is nothing to send; use send_mail for letters".to_string());
+    }
+
+    if item_ids.len() > MAX_ATTACHMENTS {
+        return Err(format!("A parcel holds at most {} items", MAX_ATTACHMENTS));
+    }
+
+    for (i, item_id) in item_ids.iter().enumerate() {
+        if item_ids[..i].contains(item_id) {
+            return Err("You listed the same item twice".to_string());
+        }
+
+        if !inventory::is_carried_by(ctx, *item_id, player.id) {
+            return Err("You are not carrying that".to_string());
+        }
+
+        let item = ctx
+            .db
+            .item_data()
+            .entity_id()
+            .find(*item_id)
+            .ok_or("Item not found")?;
+
+        if item.item_type == ItemType::Gold {
+            return Err("Send coins as the parcel's gold instead".to_string());
+        }
+
+        if item.item_type == ItemType::QuestItem {
+            return Err(format!("{} won't post that", postmaster.name));
+        }
+
+        if item.is_equipped {
+            return Err("You must unequip that first".to_string());
+        }
+    }
+
+    let mail = post(ctx, &player, &recipient_name, &subject, &body, gold)?;
+
+    ledger::debit_gold(
+        ctx,
+        player.id,
+        gold,
+        GoldFlow::Mail,
+        Some(mail.recipient_id),
+        &format!("mail {} escrow", mail.id),
+    )?;
+
+    for item_id in &item_ids {
+        inventory::release_item(ctx, *item_id);
+        ctx.db.mail_attachment().insert(MailAttachment {
+            id: 0,
+            mail_id: mail.id,
+            item_id: *item_id,
+        });
+    }
+
+    let event = GameEvent {
+        id: 0,
+        room_id: player.room_id,
+        timestamp: ctx.timestamp.to_micros_since_unix_epoch(),
+        event_type: EventType::ItemInteraction,
+        event_data: format!(
+            "{{\"action\": \"send_parcel\", \"mail\": {}, \"items\": {}, \"gold\": {}}}",
+            mail.id,
+            item_ids.len(),
+            gold
+        ),
+        primary_actor: player.id,
+        secondary_actor: Some(postmaster.id),
+        requires_sight: true,
+        requires_hearing: false,
+        stealth_dc: None,
+        expires_at: ctx.timestamp.to_micros_since_unix_epoch() + 60_000_000,
+    };
+
+    ctx.db
+        .game_event()
+        .try_insert(event)
+        .map_err(|e| format!("Failed to create event: {:?}", e))?;
+
+    log::info!(
+        "{} posted a parcel to {} ({} items, {} gold)",
+        player.name,
+        recipient_name,
+        item_ids.len(),
+        gold
+    );
+    Ok(())
+}
+
+#[reducer]
+pub fn list_mail(ctx: &ReducerContext) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+
+    let mut inbox: Vec<_> = ctx.db.mail().recipient_id().filter(player.id).collect();
+    inbox.sort_by_key(|m| m.sent_at);
+
+    // Use unique markers for parsing
+    log::info!("<<<MAIL_START>>>");
+
+    if inbox.is_empty() {
+        log::info!("Your mailbox is empty.");
+    }
+
+    for mail in inbox {
+        let new = if mail.is_read { " " } else { "*" };
+        let parcel = if mail.gold > 0 || !attachments(ctx, mail.id).is_empty() {
+            " (parcel)"
+        } else {
+            ""
+        };
+        log::info!(
+            " {}[{}] {}: {}{}",
+            new,
+            mail.id,
+            mail.sender_name,
+            mail.subject,
+            parcel
+        );
+    }
+
+    log::info!("<<<MAIL_END>>>");
+
+    Ok(())
+}
+
+#[reducer]
+pub fn read_mail(ctx: &ReducerContext, mail_id: u64) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+    let mut mail = own_mail(ctx, &player, mail_id)?;
+
+    // Use unique markers for parsing
+    log::info!("<<<MAIL_START>>>");
+    log::info!("From: {}", mail.sender_name);
+    log::info!("Subject: {}", mail.subject);
+    log::info!("");
+    for line in mail.body.lines() {
+        log::info!("{}", line);
+    }
+
+    let items = attachments(ctx, mail.id);
+    if mail.gold > 0 || !items.is_empty() {
+        log::info!("");
+        log::info!("Enclosed (claim at a postmaster):");
+        if mail.gold > 0 {
+            log::info!("  {} gold", mail.gold);
+        }
+        for attachment in items {
+            if let Some(item) = ctx.db.entity().id().find(attachment.item_id) {
+                log::info!("  {}", item.name);
+            }
+        }
+    }
+    log::info!("<<<MAIL_END>>>");
+
+    if !mail.is_read {
+        mail.is_read = true;
+        ctx.db.mail().id().update(mail);
+    }
+
+    Ok(())
+}
+
+#[reducer]
+pub fn delete_mail(ctx: &ReducerContext, mail_id: u64) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+    let mail = own_mail(ctx, &player, mail_id)?;
+
+    if mail.gold > 0 || !attachments(ctx, mail.id).is_empty() {
+        return Err("Claim what was enclosed before throwing the letter away".to_string());
+    }
+
+    ctx.db.mail().id().delete(mail.id);
+
+    log::info!("{} deleted mail {}", player.name, mail_id);
+    Ok(())
+}
+
+/// Collect a letter's enclosed items and gold from a postmaster
+#[reducer]
+pub fn claim_mail(ctx: &ReducerContext, mail_id: u64) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+
+    if !player.is_alive {
+        return Err("You are dead".to_string());
+    }
+
+    let postmaster = find_postmaster(ctx, player.room_id).ok_or("There is no postmaster here")?;
+    let mut mail = own_mail(ctx, &player, mail_id)?;
+    let items = attachments(ctx, mail.id);
+
+    if mail.gold == 0 && items.is_empty() {
+        return Err("There is nothing to claim".to_string());
+    }
+
+    for attachment in &items {
+        inventory::give_item(ctx, attachment.item_id, player.id)?;
+        ctx.db.mail_attachment().id().delete(attachment.id);
+    }
+
+    let gold = mail.gold;
+    ledger::credit_gold(
+        ctx,
+        player.id,
+        gold,
+        GoldFlow::Mail,
+        Some(mail.sender_id),
+        &format!("mail {} claimed", mail.id),
+    )?;
+
+    mail.gold = 0;
+    mail.is_read = true;
+    ctx.db.mail().id().update(mail);
+
+    let event = GameEvent {
+        id: 0,
+        room_id: player.room_id,
+        timestamp: ctx.timestamp.to_micros_since_unix_epoch(),
+        event_type: EventType::ItemInteraction,
+        event_data: format!(
+            "{{\"action\": \"claim_mail\", \"mail\": {}, \"items\": {}, \"gold\": {}}}",
+            mail_id,
+            items.len(),
+            gold
+        ),
+        primary_actor: player.id,
+        secondary_actor: Some(postmaster.id),
+        requires_sight: true,
+        requires_hearing: false,
+        stealth_dc: None,
+        expires_at: ctx.timestamp.to_micros_since_unix_epoch() + 60_000_000,
+    };
+
+    ctx.db
+        .game_event()
+        .try_insert(event)
+        .map_err(|e| format!("Failed to create event: {:?}", e))?;
+
+    log::info!(
+        "{} claimed {} items and {} gold from mail {}",
+        player.name,
+        items.len(),
+        gold,
+        mail_id
+    );
+    Ok(())
+}
+
+/// Report the unread count for the client's status bar
+#[reducer]
+pub fn check_mail(ctx: &ReducerContext) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+
+    let unread = ctx
+        .db
+        .mail()
+        .recipient_id()
+        .filter(player.id)
+        .filter(|m| !m.is_read)
+        .count();
+
+    // Use unique markers for parsing
+    log::info!("<<<MAIL_UNREAD {}>>>", unread);
+
+    Ok(())
+}
@@ -1,9 +1,51 @@
+mod actions;
 mod auth;
+mod chat;
 mod combat;
+mod conditions;
+mod consumables;
+mod crafting;
+mod economy;
+mod equipment;
+mod groups;
+mod magic;
+mod mail;
 mod movement;
+mod npcs;
+mod position;
+mod ranged;
+mod regeneration;
+mod resistances;
+mod shop;
+mod speech;
+mod stealth;
+mod tracking;
+mod trade;
+mod weather;
 mod world;
 
+pub use actions::*;
 pub use auth::*;
+pub use chat::*;
 pub use combat::*;
+pub use conditions::*;
+pub use consumables::*;
+pub use crafting::*;
+pub use economy::*;
+pub use equipment::*;
+pub use groups::*;
+pub use magic::*;
+pub use mail::*;
 pub use movement::*;
+pub use npcs::*;
+pub use position::*;
+pub use ranged::*;
+pub use regeneration::*;
+pub use resistances::*;
+pub use shop::*;
+pub use speech::*;
+pub use stealth::*;
+pub use tracking::*;
+pub use trade::*;
+pub use weather::*;
 pub use world::*;
@@ -1,37 +1,47 @@
 use crate::tables::{
-    condition::condition,           // ← Add trait import
-    entity::entity,                 // ← Add trait import
-    game_event::game_event,         // ← Add trait import
-    player_session::player_session, // ← Add trait import
-    room::room,                     // ← Add trait import
+    condition::condition,   // ← Add trait import
+    entity::entity,         // ← Add trait import
+    game_event::game_event, // ← Add trait import
+    group::follower,        // ← Add trait import
+    room::room,             // ← Add trait import
+    trail::trail,           // ← Add trait import
+    ActionKind,
+    ActionType,
     ConditionType,
+    Entity,
     EventType,
     GameEvent,
+    Position,
+    QueuedAction,
+    StealthMode,
+    Trail,
 };
+use crate::utils::{combat, rate_limits, rooms, session, stealth, timing};
 use spacetimedb::{reducer, ReducerContext, Table};
 
 #[reducer]
 pub fn move_player(ctx: &ReducerContext, direction: String) -> Result<(), String> {
     log::info!("Player attempting to move: {}", direction);
 
-    let session = ctx
-        .db
-        .player_session()
-        .identity()
-        .find(&ctx.sender)
-        .ok_or("Not logged in")?;
+    let player = session::current_character(ctx)?;
+
+    rate_limits::check(ctx, ActionType::Move)?;
+    combat::require_not_fighting(ctx, &player)?;
+
+    let action = QueuedAction {
+        direction: Some(direction.clone()),
+        ..timing::action(ActionKind::Move)
+    };
 
-    if session.character_id == 0 {
-        return Err("No character selected".to_string());
+    if timing::ready_or_queue(ctx, &player, action)? {
+        perform_move(ctx, player, &direction)?;
     }
 
-    let mut player = ctx
-        .db
-        .entity()
-        .id()
-        .find(&session.character_id)
-        .ok_or("Character not found")?;
+    Ok(())
+}
 
+/// Everything that can stop a step, checked before anything is written
+pub fn check_move(ctx: &ReducerContext, player: &Entity, direction: &str) -> Result<u64, String> {
     if !player.is_alive {
         return Err("You are dead and cannot move".to_string());
     }
@@ -47,40 +57,76 @@ pub fn move_player(ctx: &ReducerContext, direction: String) -> Result<(), String
         return Err("You are comatose and cannot move".to_string());
     }
 
+    match player.position {
+        Position::Standing => {}
+        Position::Sleeping => return Err("You are asleep".to_string()),
+        _ => return Err("You need to stand up first".to_string()),
+    }
+
+    combat::require_not_fighting(ctx, player)?;
+
     let current_room = ctx
         .db
         .room()
         .id()
-        .find(&player.room_id)
+        .find(player.room_id)
         .ok_or("Current room not found")?;
 
-    let target_room_id = match direction.to_lowercase().as_str() {
-        "north" | "n" => current_room.north_exit,
-        "south" | "s" => current_room.south_exit,
-        "east" | "e" => current_room.east_exit,
-        "west" | "w" => current_room.west_exit,
-        "up" | "u" => current_room.up_exit,
-        "down" | "d" => current_room.down_exit,
-        _ => return Err(format!("Invalid direction: {}", direction)),
-    };
-
+    let target_room_id = rooms::exit_toward(&current_room, direction)?;
     let target_room_id = target_room_id.ok_or(format!("There is no exit to the {}", direction))?;
 
     let target_room = ctx
         .db
         .room()
         .id()
-        .find(&target_room_id)
+        .find(target_room_id)
         .ok_or("Target room not found")?;
 
     if !target_room.is_active {
         return Err("That passage is blocked".to_string());
     }
 
+    Ok(target_room_id)
+}
+
+/// Step an entity through an exit, bringing along anyone following them
+pub fn perform_move(
+    ctx: &ReducerContext,
+    mut player: Entity,
+    direction: &str,
+) -> Result<(), String> {
+    let target_room_id = check_move(ctx, &player, direction)?;
+    let trail_direction = rooms::normalize_direction(direction)?.to_string();
+
+    // Sneakers roll again with every step; anyone merely hidden gives themselves away
+    let stealth_dc = match stealth::current_state(ctx, player.id) {
+        Some(state) if state.mode == StealthMode::Sneaking => {
+            Some(stealth::hide(ctx, &player, StealthMode::Sneaking))
+        }
+        Some(_) => {
+            stealth::reveal(ctx, &player);
+            None
+        }
+        None => None,
+    };
+
     let player_name = player.name.clone();
     let player_id = player.id;
     let old_room_id = player.room_id;
 
+    // Leave a trail behind; careful feet leave a fainter one
+    ctx.db.trail().insert(Trail {
+        id: 0,
+        room_id: old_room_id,
+        entity_id: player_id,
+        direction: trail_direction,
+        created_at: ctx.timestamp.to_micros_since_unix_epoch(),
+        freshness: if stealth_dc.is_some() { 0.5 } else { 1.0 },
+    });
+
+    // Leaving ends any fight still held open from another room
+    combat::disengage(ctx, player_id);
+
     player.room_id = target_room_id;
     player.last_action_at = ctx.timestamp.to_micros_since_unix_epoch();
 
@@ -99,7 +145,7 @@ pub fn move_player(ctx: &ReducerContext, direction: String) -> Result<(), String
         secondary_actor: None,
         requires_sight: true,
         requires_hearing: false,
-        stealth_dc: None,
+        stealth_dc,
         expires_at: ctx.timestamp.to_micros_since_unix_epoch() + 60_000_000,
     };
 
@@ -116,38 +162,50 @@ pub fn move_player(ctx: &ReducerContext, direction: String) -> Result<(), String
         target_room_id
     );
 
+    // Followers still standing in the old room fall in behind
+    let followers: Vec<_> = ctx
+        .db
+        .follower()
+        .leader_id()
+        .filter(player_id)
+        .filter_map(|f| ctx.db.entity().id().find(f.entity_id))
+        .filter(|e| e.room_id == old_room_id)
+        .filter(|e| combat::engagement_of(ctx, e.id).is_none())
+        .filter(|e| timing::ready_in(ctx, e, timing::move_duration(ctx, e)) == 0)
+        .collect();
+
+    for follower in followers {
+        let follower_name = follower.name.clone();
+        if let Err(e) = perform_move(ctx, follower, direction) {
+            log::info!("{} can't follow {}: {}", follower_name, player_name, e);
+        }
+    }
+
     Ok(())
 }
 
 #[reducer]
 pub fn look(ctx: &ReducerContext) -> Result<(), String> {
-    let session = ctx
-        .db
-        .player_session()
-        .identity()
-        .find(&ctx.sender)
-        .ok_or("Not logged in")?;
-
-    if session.character_id == 0 {
-        return Err("No character selected".to_string());
-    }
-
-    let player = ctx
-        .db
-        .entity()
-        .id()
-        .find(&session.character_id)
-        .ok_or("Character not found")?;
+    let player = session::current_character(ctx)?;
 
     let room = ctx
         .db
         .room()
         .id()
-        .find(&player.room_id)
+        .find(player.room_id)
         .ok_or("Room not found")?;
 
     // Use unique markers for parsing
     log::info!("<<<LOOK_START>>>");
+
+    // Sleepers only know roughly where they dozed off
+    if player.position == Position::Sleeping {
+        log::info!("=== {} ===", room.name);
+        log::info!("You are asleep.");
+        log::info!("<<<LOOK_END>>>");
+        return Ok(());
+    }
+
     log::info!("=== {} ===", room.name);
     log::info!("{}", room.description);
 
@@ -157,6 +215,7 @@ pub fn look(ctx: &ReducerContext) -> Result<(), String> {
         .entity()
         .iter()
         .filter(|e| e.room_id == player.room_id && e.id != player.id && e.is_alive)
+        .filter(|e| stealth::can_see(ctx, &player, e))
         .collect();
 
     if !entities_here.is_empty() {
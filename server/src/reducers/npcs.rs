@@ -0,0 +1,265 @@
+use crate::tables::{
+    entity::entity,             // ← Add trait import
+    game_event::game_event,     // ← Add trait import
+    npc_behavior::npc_behavior, // ← Add trait import
+    room::room,                 // ← Add trait import
+    AIType,
+    ActionKind,
+    ActionType,
+    Entity,
+    EntityType,
+    EventType,
+    GameEvent,
+    QueuedAction,
+    TickKind,
+};
+use crate::utils::{combat, position, rate_limits, rooms, session, stealth, threat, ticks, timing};
+use spacetimedb::{reducer, ReducerContext, Table};
+
+const TAUNT_STAMINA_COST: i32 = 10;
+
+/// A taunt is never ignored, even by an NPC with nobody to fight
+const TAUNT_MIN_THREAT: f32 = 10.0;
+
+/// Goad an NPC into attacking you instead of whoever it is fighting
+#[reducer]
+pub fn taunt(ctx: &ReducerContext, target_id: u64) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+    rate_limits::check(ctx, ActionType::Attack)?;
+
+    check_taunt(ctx, &player, target_id)?;
+
+    let action = QueuedAction {
+        target_id: Some(target_id),
+        ..timing::action(ActionKind::Taunt)
+    };
+
+    if timing::ready_or_queue(ctx, &player, action)? {
+        perform_taunt(ctx, player, target_id)?;
+    }
+
+    Ok(())
+}
+
+/// Everything that can stop a taunt, checked before anything is written
+pub fn check_taunt(
+    ctx: &ReducerContext,
+    player: &Entity,
+    target_id: u64,
+) -> Result<Entity, String> {
+    if !player.is_alive {
+        return Err("You are dead".to_string());
+    }
+
+    position::require_awake(player)?;
+
+    let npc = ctx
+        .db
+        .entity()
+        .id()
+        .find(target_id)
+        .ok_or("Target not found")?;
+
+    if npc.room_id != player.room_id || !stealth::can_see(ctx, player, &npc) {
+        return Err(format!("{} is not here", npc.name));
+    }
+
+    if npc.entity_type != EntityType::NPC
+        || !npc.is_alive
+        || ctx.db.npc_behavior().entity_id().find(npc.id).is_none()
+    {
+        return Err(format!("{} ignores you", npc.name));
+    }
+
+    let room = ctx
+        .db
+        .room()
+        .id()
+        .find(player.room_id)
+        .ok_or("Room not found")?;
+
+    if !room.allows_combat {
+        return Err("Combat is not allowed here".to_string());
+    }
+
+    if player.stamina < TAUNT_STAMINA_COST {
+        return Err("You are too exhausted to taunt".to_string());
+    }
+
+    Ok(npc)
+}
+
+/// Draw the NPC's attention; also run from the action queue
+pub fn perform_taunt(
+    ctx: &ReducerContext,
+    mut player: Entity,
+    target_id: u64,
+) -> Result<(), String> {
+    let npc = check_taunt(ctx, &player, target_id)?;
+
+    // Jump to just above whoever the NPC hates most
+    let top = threat::top_threat(ctx, npc.id).map_or(0.0, |t| t.amount);
+    let mine = threat::threat_from(ctx, npc.id, player.id);
+    threat::add_threat(
+        ctx,
+        npc.id,
+        player.id,
+        (top * 1.1 - mine).max(TAUNT_MIN_THREAT),
+    );
+    combat::engage(ctx, &npc, player.id);
+
+    player.stamina -= TAUNT_STAMINA_COST;
+    player.last_action_at = ctx.timestamp.to_micros_since_unix_epoch();
+    let player_id = player.id;
+    let player_name = player.name.clone();
+    let room_id = player.room_id;
+    ctx.db.entity().id().update(player);
+
+    let event = GameEvent {
+        id: 0,
+        room_id,
+        timestamp: ctx.timestamp.to_micros_since_unix_epoch(),
+        event_type: EventType::Emote,
+        event_data: format!(
+            "{{\"action\": \"taunt\", \"entity_id\": {}, \"target\": {}}}",
+            player_id, npc.id
+        ),
+        primary_actor: player_id,
+        secondary_actor: Some(npc.id),
+        requires_sight: true,
+        requires_hearing: true,
+        stealth_dc: None,
+        expires_at: ctx.timestamp.to_micros_since_unix_epoch() + 60_000_000,
+    };
+
+    ctx.db
+        .game_event()
+        .try_insert(event)
+        .map_err(|e| format!("Failed to create event: {:?}", e))?;
+
+    log::info!("{} taunts {}", player_name, npc.name);
+    Ok(())
+}
+
+/// Medium tick: decay threat, pick targets and send calm NPCs home in a region
+#[reducer]
+pub fn tick_npcs(ctx: &ReducerContext, region_id: u64) {
+    if !ticks::claim(ctx, region_id, TickKind::Npcs) {
+        return;
+    }
+
+    // Gather everyone first so an NPC stepping into another room isn't seen twice
+    let npcs: Vec<_> = ctx
+        .db
+        .room()
+        .region_id()
+        .filter(region_id)
+        .flat_map(|room| {
+            ctx.db
+                .entity()
+                .room_id()
+                .filter(room.id)
+                .collect::<Vec<_>>()
+        })
+        .filter(|e| e.entity_type == EntityType::NPC)
+        .collect();
+
+    for npc in npcs {
+        let Some(behavior) = ctx.db.npc_behavior().entity_id().find(npc.id) else {
+            continue;
+        };
+
+        if !npc.is_alive {
+            threat::clear_threat(ctx, npc.id);
+            continue;
+        }
+
+        threat::decay_threat(ctx, npc.id);
+
+        // Aggressive NPCs pick fights with any player they notice (linkdead ones are left alone)
+        if behavior.ai_type == AIType::Aggressive && threat::top_threat(ctx, npc.id).is_none() {
+            let players: Vec<_> = ctx
+                .db
+                .entity()
+                .room_id()
+                .filter(npc.room_id)
+                .filter(|e| e.entity_type == EntityType::Player && e.is_alive && e.is_active)
+                .filter(|e| stealth::can_see(ctx, &npc, e))
+                .map(|e| e.id)
+                .collect();
+
+            for player_id in players {
+                threat::add_threat(ctx, npc.id, player_id, threat::MIN_THREAT);
+            }
+        }
+
+        if matches!(behavior.ai_type, AIType::Passive | AIType::Timid) {
+            continue;
+        }
+
+        match threat::pick_target(ctx, &npc) {
+            Some(target) => {
+                let current = combat::engagement_of(ctx, npc.id).map(|e| e.target_id);
+                if current != Some(target.id) {
+                    combat::engage(ctx, &npc, target.id);
+                }
+            }
+            None if threat::top_threat(ctx, npc.id).is_none()
+                && behavior.home_room != 0
+                && npc.room_id != behavior.home_room =>
+            {
+                return_home(ctx, npc, behavior.home_room);
+            }
+            None => {}
+        }
+    }
+}
+
+/// Stop fighting and take one step along the exits back toward the home
+/// room; wounds heal through normal regeneration
+fn return_home(ctx: &ReducerContext, mut npc: Entity, home_room: u64) {
+    combat::disengage(ctx, npc.id);
+    threat::clear_threat(ctx, npc.id);
+
+    let Some(next_room) = rooms::next_step(ctx, npc.room_id, home_room) else {
+        log::debug!("{} can't find a way home to room {}", npc.name, home_room);
+        return;
+    };
+
+    let now = ctx.timestamp.to_micros_since_unix_epoch();
+    let from_room = npc.room_id;
+    npc.room_id = next_room;
+    let npc_id = npc.id;
+    let npc_name = npc.name.clone();
+    ctx.db.entity().id().update(npc);
+
+    for room_id in [from_room, next_room] {
+        let event = GameEvent {
+            id: 0,
+            room_id,
+            timestamp: now,
+            event_type: EventType::Movement,
+            event_data: format!(
+                "{{\"action\": \"return_home\", \"entity_id\": {}, \"from\": {}, \"to\": {}, \"home\": {}}}",
+                npc_id, from_room, next_room, home_room
+            ),
+            primary_actor: npc_id,
+            secondary_actor: None,
+            requires_sight: true,
+            requires_hearing: false,
+            stealth_dc: None,
+            expires_at: now + 60_000_000,
+        };
+
+        if let Err(e) = ctx.db.game_event().try_insert(event) {
+            log::error!("Failed to create movement event: {:?}", e);
+        }
+    }
+
+    log::info!(
+        "{} heads home to room {}, stepping into room {}",
+        npc_name,
+        home_room,
+        next_room
+    );
+}
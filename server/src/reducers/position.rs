@@ -0,0 +1,80 @@
+use crate::tables::{
+    entity::entity,         // ← Add trait import
+    game_event::game_event, // ← Add trait import
+    EventType,
+    GameEvent,
+    Position,
+};
+use crate::utils::session;
+use spacetimedb::{reducer, ReducerContext, Table};
+
+#[reducer]
+pub fn stand(ctx: &ReducerContext) -> Result<(), String> {
+    set_position(ctx, Position::Standing)
+}
+
+#[reducer]
+pub fn sit(ctx: &ReducerContext) -> Result<(), String> {
+    set_position(ctx, Position::Sitting)
+}
+
+#[reducer]
+pub fn rest(ctx: &ReducerContext) -> Result<(), String> {
+    set_position(ctx, Position::Resting)
+}
+
+#[reducer]
+pub fn sleep(ctx: &ReducerContext) -> Result<(), String> {
+    set_position(ctx, Position::Sleeping)
+}
+
+fn set_position(ctx: &ReducerContext, position: Position) -> Result<(), String> {
+    let mut player = session::current_character(ctx)?;
+
+    if !player.is_alive {
+        return Err("You are dead".to_string());
+    }
+
+    if player.position == position {
+        return Err(match position {
+            Position::Standing => "You are already standing",
+            Position::Sitting => "You are already sitting",
+            Position::Resting => "You are already resting",
+            Position::Sleeping => "You are already asleep",
+        }
+        .to_string());
+    }
+
+    player.position = position;
+    player.last_action_at = ctx.timestamp.to_micros_since_unix_epoch();
+
+    let player_id = player.id;
+    let room_id = player.room_id;
+    let player_name = player.name.clone();
+    ctx.db.entity().id().update(player);
+
+    let event = GameEvent {
+        id: 0,
+        room_id,
+        timestamp: ctx.timestamp.to_micros_since_unix_epoch(),
+        event_type: EventType::Emote,
+        event_data: format!(
+            "{{\"action\": \"position\", \"entity_id\": {}, \"position\": \"{:?}\"}}",
+            player_id, position
+        ),
+        primary_actor: player_id,
+        secondary_actor: None,
+        requires_sight: true,
+        requires_hearing: false,
+        stealth_dc: None,
+        expires_at: ctx.timestamp.to_micros_since_unix_epoch() + 60_000_000,
+    };
+
+    ctx.db
+        .game_event()
+        .try_insert(event)
+        .map_err(|e| format!("Failed to create event: {:?}", e))?;
+
+    log::info!("{} is now {:?}", player_name, position);
+    Ok(())
+}
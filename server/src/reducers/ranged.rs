@@ -0,0 +1,285 @@
+use crate::tables::{
+    entity::entity,               // ← Add trait import
+    game_event::game_event,       // ← Add trait import
+    item_template::item_template, // ← Add trait import
+    room::room,                   // ← Add trait import
+    ActionKind,
+    ActionType,
+    Entity,
+    EventType,
+    GameEvent,
+    ItemData,
+    ItemTemplate,
+    QueuedAction,
+    Room,
+    SkillType,
+};
+use crate::utils::{
+    combat, combat_stats, groups, inventory, position, rate_limits, resistances, rooms, session,
+    skills, stealth, threat, timing,
+};
+use dogmud_common::{apply_armor, apply_resistance, calculate_base_damage, range_modifier};
+use spacetimedb::{reducer, ReducerContext, Table};
+
+/// Fire the wielded launcher at a target up to its range away along `direction`
+#[reducer]
+pub fn shoot(ctx: &ReducerContext, target_id: u64, direction: String) -> Result<(), String> {
+    let shooter = session::current_character(ctx)?;
+    rate_limits::check(ctx, ActionType::Attack)?;
+
+    let action = QueuedAction {
+        target_id: Some(target_id),
+        direction: Some(direction.clone()),
+        ..timing::action(ActionKind::Shoot)
+    };
+
+    if timing::ready_or_queue(ctx, &shooter, action)? {
+        perform_shoot(ctx, shooter, target_id, &direction)?;
+    }
+
+    Ok(())
+}
+
+/// A shot that has passed every check
+pub struct Shot {
+    launcher: ItemData,
+    launcher_template: ItemTemplate,
+    ammo_template: ItemTemplate,
+    target: Entity,
+    target_room: Room,
+    distance: u8,
+}
+
+/// Everything that can stop a shot, checked before anything is written
+pub fn check_shoot(
+    ctx: &ReducerContext,
+    shooter: &Entity,
+    target_id: u64,
+    direction: &str,
+) -> Result<Shot, String> {
+    if !shooter.is_alive {
+        return Err("You are dead".to_string());
+    }
+
+    position::require_awake(shooter)?;
+
+    if shooter.stamina < combat::ATTACK_STAMINA_COST {
+        return Err("Not enough stamina to shoot".to_string());
+    }
+
+    let launcher =
+        inventory::wielded_weapon(ctx, shooter.id).ok_or("You aren't wielding anything")?;
+    let launcher_template = launcher
+        .template_id
+        .and_then(|id| ctx.db.item_template().id().find(id))
+        .filter(|t| t.range > 0)
+        .ok_or("You can't shoot with that")?;
+    let ammo_template = launcher_template
+        .ammo_template_id
+        .and_then(|id| ctx.db.item_template().id().find(id))
+        .ok_or("You can't shoot with that")?;
+
+    if inventory::count_from_template(ctx, shooter.id, ammo_template.id) == 0 {
+        return Err(format!("You are out of {}", ammo_template.name));
+    }
+
+    let origin = ctx
+        .db
+        .room()
+        .id()
+        .find(shooter.room_id)
+        .ok_or("Room not found")?;
+
+    if !origin.allows_combat {
+        return Err("Combat is not allowed here".to_string());
+    }
+
+    let target = ctx
+        .db
+        .entity()
+        .id()
+        .find(target_id)
+        .ok_or("Target not found")?;
+
+    if !target.is_alive {
+        return Err("Target is already dead".to_string());
+    }
+
+    // Follow the exits until we find the target or run out of range
+    let mut room = origin;
+    let mut distance = 0;
+    let target_room = loop {
+        if distance >= launcher_template.range {
+            return Err("Your target is out of range".to_string());
+        }
+
+        let next_id =
+            rooms::exit_toward(&room, direction)?.ok_or("Your shot would only hit a wall")?;
+        room = ctx
+            .db
+            .room()
+            .id()
+            .find(next_id)
+            .filter(|r| r.is_active)
+            .ok_or("Something blocks your line of fire")?;
+        distance += 1;
+
+        if room.id == target.room_id {
+            break room;
+        }
+    };
+
+    if !stealth::can_see(ctx, shooter, &target) {
+        return Err(format!("{} is not there", target.name));
+    }
+
+    if !target_room.allows_combat {
+        return Err("Combat is not allowed there".to_string());
+    }
+
+    Ok(Shot {
+        launcher,
+        launcher_template,
+        ammo_template,
+        target,
+        target_room,
+        distance,
+    })
+}
+
+/// Loose one shot; also run from the action queue
+pub fn perform_shoot(
+    ctx: &ReducerContext,
+    mut shooter: Entity,
+    target_id: u64,
+    direction: &str,
+) -> Result<(), String> {
+    let Shot {
+        launcher,
+        launcher_template,
+        ammo_template,
+        mut target,
+        target_room,
+        distance,
+    } = check_shoot(ctx, &shooter, target_id, direction)?;
+
+    inventory::consume_from_template(ctx, shooter.id, ammo_template.id, 1)?;
+    stealth::reveal(ctx, &shooter);
+    position::wake(&mut target);
+
+    let attack_stat = ((shooter.dexterity as u16 + shooter.perception as u16) / 2) as u8;
+    let attack_skill = skills::skill_level(ctx, shooter.id, SkillType::RangedCombat);
+    let attack_roll =
+        combat_stats::calculate_roll_base(attack_stat, attack_skill, range_modifier(distance));
+    let attack_sample = combat_stats::random_variance(attack_roll, ctx);
+
+    let defense_stat = ((target.dexterity as u16 + target.perception as u16) / 2) as u8;
+    let defense_roll = combat_stats::calculate_roll_base(defense_stat, 0, 0.9);
+    let defense_sample = combat_stats::random_variance(defense_roll, ctx);
+
+    let hit = attack_sample > defense_sample;
+    let is_crit = hit && combat_stats::is_critical_hit(attack_sample, defense_sample);
+    let damage_type = ammo_template.damage_type;
+    let location = combat::roll_hit_location(ctx);
+
+    let mut damage = 0;
+    if hit {
+        let base_damage = calculate_base_damage(
+            launcher
+                .base_damage
+                .saturating_add(ammo_template.base_damage),
+            shooter.dexterity,
+            attack_skill,
+        );
+
+        if is_crit {
+            // Critical hits bypass armor, but not innate resistances
+            let multiplier = resistances::damage_multiplier(ctx, target.id, damage_type, &[]);
+            damage = apply_resistance((base_damage as f32 * 1.3) as u16, multiplier) as i32;
+            combat::critical_effect(ctx, target.id, location, shooter.id);
+        } else {
+            let armor = combat::armor_at(ctx, target.id, location);
+            let armor_rating = armor.iter().map(|a| a.armor_rating).sum();
+            let multiplier = resistances::damage_multiplier(ctx, target.id, damage_type, &armor);
+            damage = apply_resistance(apply_armor(base_damage, armor_rating), multiplier) as i32;
+            combat::wear_armor(ctx, &target, armor);
+        }
+
+        target.hp = (target.hp - damage).max(0);
+        threat::add_threat(ctx, target.id, shooter.id, damage as f32);
+        if target.hp == 0 {
+            target.is_alive = false;
+            combat::disengage(ctx, target.id);
+            log::info!("{} has died!", target.name);
+        }
+    }
+
+    if inventory::wear_item(ctx, launcher, 1) {
+        log::info!("{}'s {} breaks!", shooter.name, launcher_template.name);
+    }
+
+    // Spent ammunition ends up where it was shot
+    inventory::spawn_item(ctx, &ammo_template, 1, target_room.id)?;
+
+    let now = ctx.timestamp.to_micros_since_unix_epoch();
+    shooter.stamina = (shooter.stamina - combat::ATTACK_STAMINA_COST).max(0);
+    shooter.last_action_at = now;
+
+    let shooter_id = shooter.id;
+    let shooter_name = shooter.name.clone();
+    let origin_id = shooter.room_id;
+    let target_name = target.name.clone();
+    let killed = !target.is_alive;
+    ctx.db.entity().id().update(shooter);
+    ctx.db.entity().id().update(target);
+
+    if killed {
+        groups::share_kill(ctx, shooter_id, target_id);
+    }
+
+    for room_id in [origin_id, target_room.id] {
+        let event = GameEvent {
+            id: 0,
+            room_id,
+            timestamp: now,
+            event_type: EventType::Combat,
+            event_data: format!(
+                "{{\"action\": \"shoot\", \"attacker\": {}, \"target\": {}, \"from_room\": {}, \"to_room\": {}, \"location\": \"{:?}\", \"damage\": {}, \"damage_type\": \"{:?}\", \"hit\": {}, \"critical\": {}}}",
+                shooter_id, target_id, origin_id, target_room.id, location, damage, damage_type, hit, is_crit
+            ),
+            primary_actor: shooter_id,
+            secondary_actor: Some(target_id),
+            requires_sight: true,
+            requires_hearing: true,
+            stealth_dc: None,
+            expires_at: now + 60_000_000,
+        };
+
+        ctx.db
+            .game_event()
+            .try_insert(event)
+            .map_err(|e| format!("Failed to create event: {:?}", e))?;
+    }
+
+    if let Some(level) = skills::practice_skill(ctx, shooter_id, SkillType::RangedCombat, 10) {
+        log::info!("{}'s RangedCombat improves to {}", shooter_name, level);
+    }
+
+    if hit {
+        log::info!(
+            "Combat: {} shoots {} {} for {} damage",
+            shooter_name,
+            target_name,
+            direction,
+            damage
+        );
+    } else {
+        log::info!(
+            "Combat: {} shoots at {} and misses",
+            shooter_name,
+            target_name
+        );
+    }
+
+    Ok(())
+}
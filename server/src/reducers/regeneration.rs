@@ -0,0 +1,79 @@
+use crate::tables::{
+    entity::entity, // ← Add trait import
+    room::room,     // ← Add trait import
+    ConditionType,
+    EntityType,
+    TickKind,
+};
+use crate::utils::{conditions, position, ticks};
+use dogmud_common::{hp_regen, mana_regen, max_hp, max_mana, scale_regen, stamina_regen};
+use spacetimedb::{reducer, ReducerContext};
+
+const SAFE_ZONE_MULTIPLIER: f32 = 1.5;
+
+/// Medium tick: restore HP, stamina and mana for everyone in a region
+#[reducer]
+pub fn tick_regeneration(ctx: &ReducerContext, region_id: u64) {
+    if !ticks::claim(ctx, region_id, TickKind::Regeneration) {
+        return;
+    }
+
+    for room in ctx.db.room().region_id().filter(region_id) {
+        let entities: Vec<_> = ctx
+            .db
+            .entity()
+            .room_id()
+            .filter(room.id)
+            .filter(|e| e.is_alive && matches!(e.entity_type, EntityType::Player | EntityType::NPC))
+            .collect();
+
+        for mut entity in entities {
+            let before = (
+                entity.hp,
+                entity.max_hp,
+                entity.stamina,
+                entity.mana,
+                entity.max_mana,
+            );
+
+            // Pools follow the stats they derive from
+            entity.max_hp = max_hp(entity.vitality);
+            entity.max_mana = max_mana(entity.perception, entity.willpower);
+
+            let mut multiplier = position::regen_multiplier(entity.position);
+            if room.is_safe_zone {
+                multiplier *= SAFE_ZONE_MULTIPLIER;
+            }
+
+            // Poison stops wounds closing; Regenerating speeds it up
+            let hp_multiplier =
+                if conditions::has_condition(ctx, entity.id, ConditionType::Poisoned) {
+                    0.0
+                } else if conditions::has_condition(ctx, entity.id, ConditionType::Regenerating) {
+                    multiplier * 2.0
+                } else {
+                    multiplier
+                };
+
+            entity.hp = (entity.hp + scale_regen(hp_regen(entity.vitality), hp_multiplier))
+                .min(entity.max_hp);
+            entity.stamina = (entity.stamina
+                + scale_regen(stamina_regen(entity.vitality, entity.willpower), multiplier))
+            .min(entity.max_stamina);
+            entity.mana = (entity.mana
+                + scale_regen(mana_regen(entity.perception, entity.willpower), multiplier))
+            .min(entity.max_mana);
+
+            let after = (
+                entity.hp,
+                entity.max_hp,
+                entity.stamina,
+                entity.mana,
+                entity.max_mana,
+            );
+            if after != before {
+                ctx.db.entity().id().update(entity);
+            }
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use crate::tables::{
+    entity::entity,                       // ← Add trait import
+    innate_resistance::innate_resistance, // ← Add trait import
+    server_config::server_config,         // ← Add trait import
+    ArmorType,
+    DamageType,
+    InnateResistance,
+    ServerConfig,
+};
+use crate::utils::{resistances, session};
+use spacetimedb::{reducer, ReducerContext, Table};
+
+/// Override one cell of the armor × damage type matrix
+#[reducer]
+pub fn set_resistance(
+    ctx: &ReducerContext,
+    armor_type: ArmorType,
+    damage_type: DamageType,
+    multiplier: f32,
+) -> Result<(), String> {
+    session::require_admin(ctx)?;
+
+    if !(0.0..=10.0).contains(&multiplier) {
+        return Err("Multiplier must be between 0 and 10".to_string());
+    }
+
+    let config = ServerConfig {
+        key: resistances::config_key(armor_type, damage_type),
+        value: multiplier.to_string(),
+        last_updated: ctx.timestamp.to_micros_since_unix_epoch(),
+        updated_by: Some(ctx.sender),
+    };
+
+    if ctx
+        .db
+        .server_config()
+        .key()
+        .find(config.key.clone())
+        .is_some()
+    {
+        ctx.db.server_config().key().update(config);
+    } else {
+        ctx.db
+            .server_config()
+            .try_insert(config)
+            .map_err(|e| format!("Failed to save config: {:?}", e))?;
+    }
+
+    log::info!(
+        "{:?} armor now takes {}x {:?} damage (default {})",
+        armor_type,
+        multiplier,
+        damage_type,
+        resistances::default_armor_multiplier(armor_type, damage_type)
+    );
+    Ok(())
+}
+
+/// Give an entity a natural resistance or vulnerability; 1.0 removes it
+#[reducer]
+pub fn set_innate_resistance(
+    ctx: &ReducerContext,
+    entity_id: u64,
+    damage_type: DamageType,
+    multiplier: f32,
+) -> Result<(), String> {
+    session::require_admin(ctx)?;
+
+    if !(0.0..=10.0).contains(&multiplier) {
+        return Err("Multiplier must be between 0 and 10".to_string());
+    }
+
+    let entity = ctx
+        .db
+        .entity()
+        .id()
+        .find(entity_id)
+        .ok_or("Entity not found")?;
+
+    let existing = ctx
+        .db
+        .innate_resistance()
+        .entity_id()
+        .filter(entity_id)
+        .find(|r| r.damage_type == damage_type);
+
+    match existing {
+        Some(resistance) if multiplier == 1.0 => {
+            ctx.db.innate_resistance().id().delete(resistance.id);
+        }
+        Some(mut resistance) => {
+            resistance.multiplier = multiplier;
+            ctx.db.innate_resistance().id().update(resistance);
+        }
+        None if multiplier != 1.0 => {
+            ctx.db
+                .innate_resistance()
+                .try_insert(InnateResistance {
+                    id: 0,
+                    entity_id,
+                    damage_type,
+                    multiplier,
+                })
+                .map_err(|e| format!("Failed to save resistance: {:?}", e))?;
+        }
+        None => {}
+    }
+
+    log::info!(
+        "{} now takes {}x {:?} damage",
+        entity.name,
+        multiplier,
+        damage_type
+    );
+    Ok(())
+}
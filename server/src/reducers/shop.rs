@@ -0,0 +1,300 @@
+use crate::tables::{
+    game_event::game_event,       // ← Add trait import
+    item_data::item_data,         // ← Add trait import
+    item_template::item_template, // ← Add trait import
+    room::room,                   // ← Add trait import
+    shop_stock::shop_stock,       // ← Add trait import
+    Entity,
+    EventType,
+    GameEvent,
+    GoldFlow,
+    ItemType,
+    NPCRole,
+    SkillType,
+};
+use crate::utils::{inventory, ledger, npc, session, skills};
+use dogmud_common::{buy_price, sell_price};
+use spacetimedb::{reducer, ReducerContext, Table};
+
+/// Shops restock at most once per five minutes
+const RESTOCK_INTERVAL_MICROS: i64 = 300_000_000;
+
+fn find_shopkeeper(ctx: &ReducerContext, room_id: u64) -> Option<Entity> {
+    npc::find_npc_with_role(ctx, room_id, NPCRole::Shopkeeper)
+}
+
+#[reducer]
+pub fn list_wares(ctx: &ReducerContext) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+
+    let shopkeeper = find_shopkeeper(ctx, player.room_id).ok_or("There is no shop here")?;
+    let haggling = skills::skill_level(ctx, player.id, SkillType::Haggling);
+
+    // Use unique markers for parsing
+    log::info!("<<<WARES_START>>>");
+    log::info!("{} offers:", shopkeeper.name);
+
+    let mut any = false;
+    for stock in ctx.db.shop_stock().shopkeeper_id().filter(shopkeeper.id) {
+        let Some(template) = ctx.db.item_template().id().find(stock.template_id) else {
+            continue;
+        };
+
+        if stock.quantity == 0 {
+            log::info!("  [{}] {} - sold out", stock.id, template.name);
+        } else {
+            let price = buy_price(
+                template.base_value,
+                template.max_durability,
+                template.max_durability,
+                haggling,
+            );
+            log::info!(
+                "  [{}] {} - {} gold ({} in stock)",
+                stock.id,
+                template.name,
+                price,
+                stock.quantity
+            );
+        }
+        any = true;
+    }
+
+    if !any {
+        log::info!("  Nothing at the moment.");
+    }
+
+    log::info!("<<<WARES_END>>>");
+
+    Ok(())
+}
+
+#[reducer]
+pub fn buy(ctx: &ReducerContext, stock_id: u64) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+
+    if !player.is_alive {
+        return Err("You are dead".to_string());
+    }
+
+    let mut stock = ctx
+        .db
+        .shop_stock()
+        .id()
+        .find(stock_id)
+        .ok_or("That item is not for sale")?;
+
+    let shopkeeper = find_shopkeeper(ctx, player.room_id).ok_or("There is no shop here")?;
+
+    if stock.shopkeeper_id != shopkeeper.id {
+        return Err(format!("{} does not sell that", shopkeeper.name));
+    }
+
+    if stock.quantity == 0 {
+        return Err("That item is sold out".to_string());
+    }
+
+    let template = ctx
+        .db
+        .item_template()
+        .id()
+        .find(stock.template_id)
+        .ok_or("Item template not found")?;
+
+    let haggling = skills::skill_level(ctx, player.id, SkillType::Haggling);
+    let price = buy_price(
+        template.base_value,
+        template.max_durability,
+        template.max_durability,
+        haggling,
+    );
+
+    ledger::debit_gold(
+        ctx,
+        player.id,
+        price,
+        GoldFlow::Shop,
+        Some(shopkeeper.id),
+        &format!("bought {}", template.name),
+    )?;
+    let item_id = inventory::give_from_template(ctx, &template, 1, player.id)?;
+
+    stock.quantity -= 1;
+    ctx.db.shop_stock().id().update(stock);
+
+    let event = GameEvent {
+        id: 0,
+        room_id: player.room_id,
+        timestamp: ctx.timestamp.to_micros_since_unix_epoch(),
+        event_type: EventType::Economy,
+        event_data: format!(
+            "{{\"action\": \"buy\", \"item\": {}, \"template\": {}, \"price\": {}}}",
+            item_id, template.id, price
+        ),
+        primary_actor: player.id,
+        secondary_actor: Some(shopkeeper.id),
+        requires_sight: true,
+        requires_hearing: false,
+        stealth_dc: None,
+        expires_at: ctx.timestamp.to_micros_since_unix_epoch() + 60_000_000,
+    };
+
+    ctx.db
+        .game_event()
+        .try_insert(event)
+        .map_err(|e| format!("Failed to create event: {:?}", e))?;
+
+    log::info!(
+        "{} bought {} from {} for {} gold",
+        player.name,
+        template.name,
+        shopkeeper.name,
+        price
+    );
+
+    Ok(())
+}
+
+#[reducer]
+pub fn sell(ctx: &ReducerContext, item_id: u64) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+
+    if !player.is_alive {
+        return Err("You are dead".to_string());
+    }
+
+    let shopkeeper = find_shopkeeper(ctx, player.room_id).ok_or("There is no shop here")?;
+
+    if !inventory::is_carried_by(ctx, item_id, player.id) {
+        return Err("You are not carrying that".to_string());
+    }
+
+    let mut item = ctx
+        .db
+        .item_data()
+        .entity_id()
+        .find(item_id)
+        .ok_or("Item not found")?;
+
+    if item.item_type == ItemType::Gold {
+        return Err("The shopkeeper already has plenty of coins".to_string());
+    }
+
+    if item.item_type == ItemType::QuestItem {
+        return Err(format!("{} won't take that", shopkeeper.name));
+    }
+
+    if item.is_equipped {
+        return Err("You must unequip that first".to_string());
+    }
+
+    let haggling = skills::skill_level(ctx, player.id, SkillType::Haggling);
+    let price = sell_price(
+        item.base_value,
+        item.durability,
+        item.max_durability,
+        haggling,
+    );
+
+    if price == 0 {
+        return Err(format!("{} isn't interested in that", shopkeeper.name));
+    }
+
+    // Sell a single unit off a stack
+    let template_id = item.template_id;
+    if item.quantity > 1 {
+        item.quantity -= 1;
+        ctx.db.item_data().entity_id().update(item);
+    } else {
+        inventory::destroy_item(ctx, item_id);
+    }
+
+    // Goods the shop already stocks go back on the shelf
+    if let Some(template_id) = template_id {
+        let stock = ctx
+            .db
+            .shop_stock()
+            .shopkeeper_id()
+            .filter(shopkeeper.id)
+            .find(|s| s.template_id == template_id && s.quantity < s.max_quantity);
+
+        if let Some(mut stock) = stock {
+            stock.quantity += 1;
+            ctx.db.shop_stock().id().update(stock);
+        }
+    }
+
+    ledger::credit_gold(
+        ctx,
+        player.id,
+        price,
+        GoldFlow::Shop,
+        Some(shopkeeper.id),
+        &format!("sold item {}", item_id),
+    )?;
+
+    let event = GameEvent {
+        id: 0,
+        room_id: player.room_id,
+        timestamp: ctx.timestamp.to_micros_since_unix_epoch(),
+        event_type: EventType::Economy,
+        event_data: format!(
+            "{{\"action\": \"sell\", \"item\": {}, \"price\": {}}}",
+            item_id, price
+        ),
+        primary_actor: player.id,
+        secondary_actor: Some(shopkeeper.id),
+        requires_sight: true,
+        requires_hearing: false,
+        stealth_dc: None,
+        expires_at: ctx.timestamp.to_micros_since_unix_epoch() + 60_000_000,
+    };
+
+    ctx.db
+        .game_event()
+        .try_insert(event)
+        .map_err(|e| format!("Failed to create event: {:?}", e))?;
+
+    log::info!(
+        "{} sold item {} to {} for {} gold",
+        player.name,
+        item_id,
+        shopkeeper.name,
+        price
+    );
+
+    Ok(())
+}
+
+/// Medium tick: top up shop shelves in a region
+#[reducer]
+pub fn tick_shops(ctx: &ReducerContext, region_id: u64) {
+    let now = ctx.timestamp.to_micros_since_unix_epoch();
+    let mut restocked = 0;
+
+    for room in ctx.db.room().region_id().filter(region_id) {
+        let Some(shopkeeper) = find_shopkeeper(ctx, room.id) else {
+            continue;
+        };
+
+        let due: Vec<_> = ctx
+            .db
+            .shop_stock()
+            .shopkeeper_id()
+            .filter(shopkeeper.id)
+            .filter(|s| s.quantity < s.max_quantity)
+            .filter(|s| now - s.last_restock >= RESTOCK_INTERVAL_MICROS)
+            .collect();
+
+        for mut stock in due {
+            stock.quantity = (stock.quantity + stock.restock_amount).min(stock.max_quantity);
+            stock.last_restock = now;
+            ctx.db.shop_stock().id().update(stock);
+            restocked += 1;
+        }
+    }
+
+    if restocked > 0 {
+        log::debug!("Restocked {} shop items in region {}", restocked, region_id);
+    }
+}
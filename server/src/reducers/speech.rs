@@ -0,0 +1,250 @@
+use crate::tables::{
+    entity::entity,         // ← Add trait import
+    game_event::game_event, // ← Add trait import
+    room::room,             // ← Add trait import
+    ActionType,
+    ConditionType,
+    Entity,
+    EventType,
+    GameEvent,
+    Position,
+};
+use crate::utils::{conditions, position, rate_limits, rooms, session, stealth};
+use spacetimedb::{reducer, ReducerContext, Table};
+
+/// Longest line anyone can say in one go
+const MAX_SPEECH_LEN: usize = 256;
+
+const DIRECTIONS: [&str; 6] = ["north", "south", "east", "west", "up", "down"];
+
+fn opposite(direction: &str) -> &'static str {
+    match direction {
+        "north" => "south",
+        "south" => "north",
+        "east" => "west",
+        "west" => "east",
+        "up" => "down",
+        _ => "up",
+    }
+}
+
+/// The speaker, once they are able to talk and within their rate limit
+fn speaker(ctx: &ReducerContext) -> Result<Entity, String> {
+    let player = session::current_character(ctx)?;
+
+    if !player.is_alive {
+        return Err("You are dead".to_string());
+    }
+
+    if conditions::has_condition(ctx, player.id, ConditionType::Comatose) {
+        return Err("You are comatose".to_string());
+    }
+
+    position::require_awake(&player)?;
+    rate_limits::check(ctx, ActionType::Speech)?;
+
+    Ok(player)
+}
+
+/// Trim and validate text, returning it as a JSON string literal
+fn quoted(text: &str) -> Result<String, String> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Err("Say what?".to_string());
+    }
+
+    if text.len() > MAX_SPEECH_LEN {
+        return Err("That's too much to say at once".to_string());
+    }
+
+    serde_json::to_string(text).map_err(|e| e.to_string())
+}
+
+fn can_hear(ctx: &ReducerContext, listener: &Entity) -> bool {
+    listener.is_alive
+        && listener.position != Position::Sleeping
+        && !conditions::has_condition(ctx, listener.id, ConditionType::Comatose)
+        && !conditions::has_condition(ctx, listener.id, ConditionType::Deafened)
+}
+
+fn speech_event(
+    ctx: &ReducerContext,
+    room_id: u64,
+    event_type: EventType,
+    event_data: String,
+    speaker_id: u64,
+    listener_id: Option<u64>,
+) -> Result<(), String> {
+    let is_speech = event_type == EventType::Speech;
+    let event = GameEvent {
+        id: 0,
+        room_id,
+        timestamp: ctx.timestamp.to_micros_since_unix_epoch(),
+        event_type,
+        event_data,
+        primary_actor: speaker_id,
+        secondary_actor: listener_id,
+        requires_sight: !is_speech,
+        requires_hearing: is_speech,
+        stealth_dc: None,
+        expires_at: ctx.timestamp.to_micros_since_unix_epoch() + 60_000_000,
+    };
+
+    ctx.db
+        .game_event()
+        .try_insert(event)
+        .map(|_| ())
+        .map_err(|e| format!("Failed to create event: {:?}", e))
+}
+
+/// Speak to everyone in the room
+#[reducer]
+pub fn say(ctx: &ReducerContext, text: String) -> Result<(), String> {
+    let player = speaker(ctx)?;
+    let quoted = quoted(&text)?;
+
+    stealth::reveal(ctx, &player);
+
+    speech_event(
+        ctx,
+        player.room_id,
+        EventType::Speech,
+        format!(
+            "{{\"action\": \"say\", \"speaker\": {}, \"text\": {}}}",
+            player.id, quoted
+        ),
+        player.id,
+        None,
+    )?;
+
+    log::info!("{} says: {}", player.name, text.trim());
+    Ok(())
+}
+
+/// Speak quietly to one person; others only see lips moving
+#[reducer]
+pub fn whisper(ctx: &ReducerContext, target_id: u64, text: String) -> Result<(), String> {
+    let player = speaker(ctx)?;
+    let quoted = quoted(&text)?;
+
+    let target = ctx
+        .db
+        .entity()
+        .id()
+        .find(target_id)
+        .ok_or("Target not found")?;
+
+    if target.id == player.id {
+        return Err("You mutter to yourself".to_string());
+    }
+
+    if target.room_id != player.room_id || !stealth::can_see(ctx, &player, &target) {
+        return Err(format!("{} is not here", target.name));
+    }
+
+    if !can_hear(ctx, &target) {
+        return Err(format!("{} can't hear you", target.name));
+    }
+
+    // Clients show the text to the listener only
+    speech_event(
+        ctx,
+        player.room_id,
+        EventType::Speech,
+        format!(
+            "{{\"action\": \"whisper\", \"speaker\": {}, \"listener\": {}, \"text\": {}}}",
+            player.id, target.id, quoted
+        ),
+        player.id,
+        Some(target.id),
+    )?;
+
+    log::info!(
+        "{} whispers to {}: {}",
+        player.name,
+        target.name,
+        text.trim()
+    );
+    Ok(())
+}
+
+/// Shout loud enough to carry, muffled, into every neighbouring room
+#[reducer]
+pub fn yell(ctx: &ReducerContext, text: String) -> Result<(), String> {
+    let player = speaker(ctx)?;
+    let quoted = quoted(&text)?;
+
+    let room = ctx
+        .db
+        .room()
+        .id()
+        .find(player.room_id)
+        .ok_or("Room not found")?;
+
+    stealth::reveal(ctx, &player);
+
+    speech_event(
+        ctx,
+        room.id,
+        EventType::Speech,
+        format!(
+            "{{\"action\": \"yell\", \"speaker\": {}, \"text\": {}, \"muffled\": false}}",
+            player.id, quoted
+        ),
+        player.id,
+        None,
+    )?;
+
+    let mut heard_in = vec![room.id];
+    for direction in DIRECTIONS {
+        let Some(neighbour_id) = rooms::exit_toward(&room, direction)? else {
+            continue;
+        };
+
+        if heard_in.contains(&neighbour_id) {
+            continue;
+        }
+        heard_in.push(neighbour_id);
+
+        speech_event(
+            ctx,
+            neighbour_id,
+            EventType::Speech,
+            format!(
+                "{{\"action\": \"yell\", \"speaker\": {}, \"text\": {}, \"muffled\": true, \"from\": \"{}\"}}",
+                player.id,
+                quoted,
+                opposite(direction)
+            ),
+            player.id,
+            None,
+        )?;
+    }
+
+    log::info!("{} yells: {}", player.name, text.trim());
+    Ok(())
+}
+
+/// Free-form action shown to everyone who can see you
+#[reducer]
+pub fn emote(ctx: &ReducerContext, text: String) -> Result<(), String> {
+    let player = speaker(ctx)?;
+    let quoted = quoted(&text)?;
+
+    stealth::reveal(ctx, &player);
+
+    speech_event(
+        ctx,
+        player.room_id,
+        EventType::Emote,
+        format!(
+            "{{\"action\": \"emote\", \"entity_id\": {}, \"text\": {}}}",
+            player.id, quoted
+        ),
+        player.id,
+        None,
+    )?;
+
+    log::info!("{} {}", player.name, text.trim());
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use crate::tables::StealthMode;
+use crate::utils::{position, session, stealth};
+use spacetimedb::{reducer, ReducerContext};
+
+#[reducer]
+pub fn hide(ctx: &ReducerContext) -> Result<(), String> {
+    toggle(ctx, StealthMode::Hidden)
+}
+
+#[reducer]
+pub fn sneak(ctx: &ReducerContext) -> Result<(), String> {
+    toggle(ctx, StealthMode::Sneaking)
+}
+
+/// Enter a stealth mode, or leave it if already in it
+fn toggle(ctx: &ReducerContext, mode: StealthMode) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+
+    if !player.is_alive {
+        return Err("You are dead".to_string());
+    }
+
+    position::require_awake(&player)?;
+
+    if let Some(current) = stealth::current_state(ctx, player.id) {
+        if current.mode == mode {
+            stealth::reveal(ctx, &player);
+            log::info!("{} stops {:?}", player.name, mode);
+            return Ok(());
+        }
+    }
+
+    // Stealth is trained by observers' contested rolls, not by toggling
+    let stealth_dc = stealth::hide(ctx, &player, mode);
+
+    log::info!("{} is now {:?} (DC {})", player.name, mode, stealth_dc);
+    Ok(())
+}
@@ -0,0 +1,144 @@
+use crate::reducers::weather::current_weather;
+use crate::tables::{
+    entity::entity, // ← Add trait import
+    region::region, // ← Add trait import
+    room::room,     // ← Add trait import
+    trail::trail,   // ← Add trait import
+    ActionType,
+    BiomeType,
+    SkillType,
+    TickKind,
+    WeatherType,
+};
+use crate::utils::{combat_stats, position, rate_limits, session, skills, ticks};
+use dogmud_common::tracking_dc;
+use spacetimedb::{reducer, ReducerContext};
+
+/// Freshness lost per medium tick in clear weather on open ground (about an hour)
+const BASE_TRACK_DECAY: f32 = 1.0 / 720.0;
+
+/// How quickly the ground gives up tracks
+fn biome_decay(biome: BiomeType) -> f32 {
+    match biome {
+        BiomeType::Ocean => 20.0,
+        BiomeType::Desert | BiomeType::City => 2.0,
+        BiomeType::Swamp | BiomeType::Mountain => 1.5,
+        BiomeType::Plains => 1.0,
+        BiomeType::Forest => 0.8,
+        BiomeType::Tundra => 0.7,
+        BiomeType::Underground | BiomeType::Dungeon => 0.5,
+    }
+}
+
+/// How much the weather speeds up decay; underground is sheltered
+fn weather_decay(biome: BiomeType, weather: WeatherType) -> f32 {
+    if matches!(biome, BiomeType::Underground | BiomeType::Dungeon) {
+        return 1.0;
+    }
+
+    match weather {
+        WeatherType::Clear | WeatherType::Cloudy => 1.0,
+        WeatherType::Snow => 1.5,
+        WeatherType::Rain => 3.0,
+        WeatherType::Storm => 5.0,
+    }
+}
+
+/// Medium tick: fade the tracks in a region and remove the ones that are gone
+#[reducer]
+pub fn tick_tracks(ctx: &ReducerContext, region_id: u64) {
+    if !ticks::claim(ctx, region_id, TickKind::Tracks) {
+        return;
+    }
+
+    let Some(region) = ctx.db.region().id().find(region_id) else {
+        return;
+    };
+
+    let decay = BASE_TRACK_DECAY
+        * biome_decay(region.biome)
+        * weather_decay(region.biome, current_weather(ctx, region_id));
+    let mut faded = 0;
+
+    for room in ctx.db.room().region_id().filter(region_id) {
+        let trails: Vec<_> = ctx.db.trail().room_id().filter(room.id).collect();
+
+        for mut trail in trails {
+            trail.freshness -= decay;
+            if trail.freshness <= 0.0 {
+                ctx.db.trail().id().delete(trail.id);
+                faded += 1;
+            } else {
+                ctx.db.trail().id().update(trail);
+            }
+        }
+    }
+
+    if faded > 0 {
+        log::debug!("{} tracks faded in region {}", faded, region_id);
+    }
+}
+
+/// Look for the trail of a named creature in the current room
+#[reducer]
+pub fn track(ctx: &ReducerContext, target_name: String) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+
+    if !player.is_alive {
+        return Err("You are dead".to_string());
+    }
+
+    position::require_awake(&player)?;
+    rate_limits::check(ctx, ActionType::Track)?;
+
+    let trail = ctx
+        .db
+        .trail()
+        .room_id()
+        .filter(player.room_id)
+        .filter(|t| t.entity_id != player.id)
+        .filter(|t| {
+            ctx.db
+                .entity()
+                .id()
+                .find(t.entity_id)
+                .is_some_and(|e| e.name.eq_ignore_ascii_case(&target_name))
+        })
+        .max_by(|a, b| a.freshness.total_cmp(&b.freshness));
+
+    let skill = skills::skill_level(ctx, player.id, SkillType::Tracking);
+    let roll = combat_stats::calculate_roll_base(player.perception, skill, 1.0);
+    let sample = combat_stats::random_variance(roll, ctx);
+
+    // Only reading a real trail teaches anything, whether or not it is found
+    if trail.is_some() {
+        if let Some(level) = skills::practice_skill(ctx, player.id, SkillType::Tracking, 5) {
+            log::info!("{}'s Tracking improves to {}", player.name, level);
+        }
+    }
+
+    let found = trail.filter(|t| sample >= tracking_dc(t.freshness));
+
+    let Some(trail) = found else {
+        log::info!("{} finds no trail of {} here", player.name, target_name);
+        return Ok(());
+    };
+
+    let age = if trail.freshness > 0.75 {
+        "fresh"
+    } else if trail.freshness > 0.25 {
+        "faint"
+    } else {
+        "barely visible"
+    };
+
+    log::info!(
+        "{} finds {} tracks of {} leading {}",
+        player.name,
+        age,
+        target_name,
+        trail.direction
+    );
+
+    Ok(())
+}
@@ -0,0 +1,385 @@
+use crate::tables::{
+    entity::entity,         // ← Add trait import
+    game_event::game_event, // ← Add trait import
+    item_data::item_data,   // ← Add trait import
+    trade::trade_offer,     // ← Add trait import
+    trade::trade_session,   // ← Add trait import
+    ActionType,
+    EntityType,
+    EventType,
+    GameEvent,
+    GoldFlow,
+    ItemType,
+    TradeOffer,
+    TradeSession,
+};
+use crate::utils::{inventory, ledger, rate_limits, session};
+use spacetimedb::{reducer, ReducerContext, Table};
+
+fn active_trade(ctx: &ReducerContext, entity_id: u64) -> Option<TradeSession> {
+    ctx.db
+        .trade_session()
+        .initiator_id()
+        .filter(entity_id)
+        .next()
+        .or_else(|| ctx.db.trade_session().partner_id().filter(entity_id).next())
+}
+
+fn close_trade(ctx: &ReducerContext, trade_id: u64) {
+    let offers: Vec<_> = ctx
+        .db
+        .trade_offer()
+        .trade_id()
+        .filter(trade_id)
+        .map(|o| o.id)
+        .collect();
+
+    for id in offers {
+        ctx.db.trade_offer().id().delete(id);
+    }
+    ctx.db.trade_session().id().delete(trade_id);
+}
+
+/// Any change to the offers invalidates both confirmations
+fn mark_changed(ctx: &ReducerContext, mut trade: TradeSession) {
+    trade.initiator_confirmed = false;
+    trade.partner_confirmed = false;
+    trade.updated_at = ctx.timestamp.to_micros_since_unix_epoch();
+    ctx.db.trade_session().id().update(trade);
+}
+
+fn trade_event(ctx: &ReducerContext, trade: &TradeSession, actor_id: u64, action: &str) {
+    let other_id = if actor_id == trade.initiator_id {
+        trade.partner_id
+    } else {
+        trade.initiator_id
+    };
+
+    let event = GameEvent {
+        id: 0,
+        room_id: trade.room_id,
+        timestamp: ctx.timestamp.to_micros_since_unix_epoch(),
+        event_type: EventType::Economy,
+        event_data: format!("{{\"action\": \"{}\", \"trade\": {}}}", action, trade.id),
+        primary_actor: actor_id,
+        secondary_actor: Some(other_id),
+        requires_sight: true,
+        requires_hearing: false,
+        stealth_dc: None,
+        expires_at: ctx.timestamp.to_micros_since_unix_epoch() + 60_000_000,
+    };
+
+    if let Err(e) = ctx.db.game_event().try_insert(event) {
+        log::error!("Failed to create trade event: {:?}", e);
+    }
+}
+
+#[reducer]
+pub fn trade_open(ctx: &ReducerContext, partner_id: u64) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+    rate_limits::check(ctx, ActionType::Trade)?;
+
+    if !player.is_alive {
+        return Err("You are dead".to_string());
+    }
+
+    let partner = ctx
+        .db
+        .entity()
+        .id()
+        .find(partner_id)
+        .ok_or("Trade partner not found")?;
+
+    if partner.id == player.id {
+        return Err("You cannot trade with yourself".to_string());
+    }
+
+    if partner.entity_type != EntityType::Player || !partner.is_alive {
+        return Err(format!("{} cannot trade", partner.name));
+    }
+
+    if partner.room_id != player.room_id {
+        return Err(format!("{} is not here", partner.name));
+    }
+
+    if active_trade(ctx, player.id).is_some() {
+        return Err("You are already trading".to_string());
+    }
+
+    if active_trade(ctx, partner.id).is_some() {
+        return Err(format!("{} is already trading", partner.name));
+    }
+
+    let now = ctx.timestamp.to_micros_since_unix_epoch();
+    let trade = ctx
+        .db
+        .trade_session()
+        .try_insert(TradeSession {
+            id: 0,
+            initiator_id: player.id,
+            partner_id: partner.id,
+            room_id: player.room_id,
+            initiator_gold: 0,
+            partner_gold: 0,
+            initiator_confirmed: false,
+            partner_confirmed: false,
+            created_at: now,
+            updated_at: now,
+        })
+        .map_err(|e| format!("Failed to open trade: {:?}", e))?;
+
+    trade_event(ctx, &trade, player.id, "trade_open");
+    log::info!("{} opened a trade with {}", player.name, partner.name);
+
+    Ok(())
+}
+
+#[reducer]
+pub fn trade_offer_item(ctx: &ReducerContext, item_id: u64) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+    rate_limits::check(ctx, ActionType::Trade)?;
+    let trade = active_trade(ctx, player.id).ok_or("You are not trading")?;
+
+    if !inventory::is_carried_by(ctx, item_id, player.id) {
+        return Err("You are not carrying that".to_string());
+    }
+
+    let item = ctx
+        .db
+        .item_data()
+        .entity_id()
+        .find(item_id)
+        .ok_or("Item not found")?;
+
+    if item.item_type == ItemType::Gold {
+        return Err("Offer coins with trade_offer_gold".to_string());
+    }
+
+    if item.is_equipped {
+        return Err("You must unequip that first".to_string());
+    }
+
+    if ctx
+        .db
+        .trade_offer()
+        .item_id()
+        .filter(item_id)
+        .next()
+        .is_some()
+    {
+        return Err("That item is already on offer".to_string());
+    }
+
+    ctx.db
+        .trade_offer()
+        .try_insert(TradeOffer {
+            id: 0,
+            trade_id: trade.id,
+            owner_id: player.id,
+            item_id,
+            quantity: item.quantity,
+            durability: item.durability,
+        })
+        .map_err(|e| format!("Failed to offer item: {:?}", e))?;
+
+    trade_event(ctx, &trade, player.id, "trade_offer");
+    mark_changed(ctx, trade);
+
+    Ok(())
+}
+
+#[reducer]
+pub fn trade_withdraw_item(ctx: &ReducerContext, item_id: u64) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+    rate_limits::check(ctx, ActionType::Trade)?;
+    let trade = active_trade(ctx, player.id).ok_or("You are not trading")?;
+
+    let offer = ctx
+        .db
+        .trade_offer()
+        .trade_id()
+        .filter(trade.id)
+        .find(|o| o.item_id == item_id && o.owner_id == player.id)
+        .ok_or("You have not offered that")?;
+
+    ctx.db.trade_offer().id().delete(offer.id);
+
+    trade_event(ctx, &trade, player.id, "trade_withdraw");
+    mark_changed(ctx, trade);
+
+    Ok(())
+}
+
+#[reducer]
+pub fn trade_offer_gold(ctx: &ReducerContext, amount: u32) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+    rate_limits::check(ctx, ActionType::Trade)?;
+    let mut trade = active_trade(ctx, player.id).ok_or("You are not trading")?;
+
+    let carried = inventory::gold_carried(ctx, player.id);
+    if carried < amount as u64 {
+        return Err(format!("You only have {} gold", carried));
+    }
+
+    if player.id == trade.initiator_id {
+        trade.initiator_gold = amount;
+    } else {
+        trade.partner_gold = amount;
+    }
+
+    trade_event(ctx, &trade, player.id, "trade_offer");
+    mark_changed(ctx, trade);
+
+    Ok(())
+}
+
+#[reducer]
+pub fn trade_confirm(ctx: &ReducerContext) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+    rate_limits::check(ctx, ActionType::Trade)?;
+    let mut trade = active_trade(ctx, player.id).ok_or("You are not trading")?;
+
+    if player.id == trade.initiator_id {
+        trade.initiator_confirmed = true;
+    } else {
+        trade.partner_confirmed = true;
+    }
+
+    if !(trade.initiator_confirmed && trade.partner_confirmed) {
+        trade_event(ctx, &trade, player.id, "trade_confirm");
+        ctx.db.trade_session().id().update(trade);
+        return Ok(());
+    }
+
+    execute_trade(ctx, trade, player.id)
+}
+
+#[reducer]
+pub fn trade_cancel(ctx: &ReducerContext) -> Result<(), String> {
+    let player = session::current_character(ctx)?;
+    let trade = active_trade(ctx, player.id).ok_or("You are not trading")?;
+
+    trade_event(ctx, &trade, player.id, "trade_cancel");
+    close_trade(ctx, trade.id);
+
+    log::info!("{} cancelled trade {}", player.name, trade.id);
+    Ok(())
+}
+
+/// Swap everything on offer in one transaction, re-checking ownership so
+/// nothing dropped, sold, split or worn down after confirming can be "traded"
+fn execute_trade(ctx: &ReducerContext, trade: TradeSession, actor_id: u64) -> Result<(), String> {
+    for entity_id in [trade.initiator_id, trade.partner_id] {
+        let trader = ctx
+            .db
+            .entity()
+            .id()
+            .find(entity_id)
+            .ok_or("Trade partner not found")?;
+
+        if !trader.is_alive || trader.room_id != trade.room_id {
+            return Err(format!("{} is no longer here", trader.name));
+        }
+    }
+
+    let offers: Vec<_> = ctx.db.trade_offer().trade_id().filter(trade.id).collect();
+
+    let mut stale = Vec::new();
+    let mut changed = Vec::new();
+    for offer in &offers {
+        let item = ctx
+            .db
+            .item_data()
+            .entity_id()
+            .find(offer.item_id)
+            .filter(|i| {
+                !i.is_equipped && inventory::is_carried_by(ctx, i.entity_id, offer.owner_id)
+            });
+
+        match item {
+            None => stale.push(offer.id),
+            Some(item)
+                if item.quantity != offer.quantity || item.durability != offer.durability =>
+            {
+                changed.push(TradeOffer {
+                    id: offer.id,
+                    trade_id: offer.trade_id,
+                    owner_id: offer.owner_id,
+                    item_id: offer.item_id,
+                    quantity: item.quantity,
+                    durability: item.durability,
+                });
+            }
+            Some(_) => {}
+        }
+    }
+
+    let initiator_short =
+        inventory::gold_carried(ctx, trade.initiator_id) < trade.initiator_gold as u64;
+    let partner_short = inventory::gold_carried(ctx, trade.partner_id) < trade.partner_gold as u64;
+
+    if !stale.is_empty() || !changed.is_empty() || initiator_short || partner_short {
+        // The offer changed under us: drop what is gone, show what it is now
+        // and ask both to re-confirm
+        for id in stale {
+            ctx.db.trade_offer().id().delete(id);
+        }
+        for offer in changed {
+            ctx.db.trade_offer().id().update(offer);
+        }
+
+        let mut trade = trade;
+        if initiator_short {
+            trade.initiator_gold = 0;
+        }
+        if partner_short {
+            trade.partner_gold = 0;
+        }
+
+        trade_event(ctx, &trade, actor_id, "trade_changed");
+        mark_changed(ctx, trade);
+
+        log::info!("Trade offers changed before completion; confirmations reset");
+        return Ok(());
+    }
+
+    for offer in &offers {
+        let recipient = if offer.owner_id == trade.initiator_id {
+            trade.partner_id
+        } else {
+            trade.initiator_id
+        };
+        inventory::give_item(ctx, offer.item_id, recipient)?;
+    }
+
+    let reason = format!("trade {}", trade.id);
+    ledger::transfer_gold(
+        ctx,
+        trade.initiator_id,
+        trade.partner_id,
+        trade.initiator_gold,
+        GoldFlow::Trade,
+        &reason,
+    )?;
+    ledger::transfer_gold(
+        ctx,
+        trade.partner_id,
+        trade.initiator_id,
+        trade.partner_gold,
+        GoldFlow::Trade,
+        &reason,
+    )?;
+
+    trade_event(ctx, &trade, actor_id, "trade_complete");
+    close_trade(ctx, trade.id);
+
+    log::info!(
+        "Trade {} complete: {} items, {} and {} gold exchanged",
+        trade.id,
+        offers.len(),
+        trade.initiator_gold,
+        trade.partner_gold
+    );
+
+    Ok(())
+}
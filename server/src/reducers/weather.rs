@@ -0,0 +1,114 @@
+use crate::tables::{
+    game_event::game_event, // ← Add trait import
+    region::region,         // ← Add trait import
+    room::room,             // ← Add trait import
+    weather::weather,       // ← Add trait import
+    ClimateType,
+    EventType,
+    GameEvent,
+    TickKind,
+    Weather,
+    WeatherType,
+};
+use crate::utils::ticks;
+use dogmud_common::pick_weighted;
+use spacetimedb::{rand::Rng, reducer, ReducerContext, Table};
+
+/// Chance per medium tick that the weather changes
+const WEATHER_CHANGE_CHANCE: f32 = 0.02;
+
+/// Relative likelihood of each kind of weather in a climate
+fn weather_weights(climate: ClimateType) -> [(WeatherType, u32); 5] {
+    use WeatherType::*;
+
+    match climate {
+        ClimateType::Tropical => [
+            (Clear, 40),
+            (Cloudy, 20),
+            (Rain, 30),
+            (Storm, 10),
+            (Snow, 0),
+        ],
+        ClimateType::Temperate => [(Clear, 50), (Cloudy, 25), (Rain, 18), (Storm, 5), (Snow, 2)],
+        ClimateType::Arctic => [(Clear, 40), (Cloudy, 30), (Rain, 0), (Storm, 5), (Snow, 25)],
+        ClimateType::Arid => [(Clear, 80), (Cloudy, 15), (Rain, 2), (Storm, 3), (Snow, 0)],
+        ClimateType::Magical => [
+            (Clear, 20),
+            (Cloudy, 20),
+            (Rain, 20),
+            (Storm, 20),
+            (Snow, 20),
+        ],
+    }
+}
+
+/// Current weather in a region, clear if it has never been rolled
+pub fn current_weather(ctx: &ReducerContext, region_id: u64) -> WeatherType {
+    ctx.db
+        .weather()
+        .region_id()
+        .find(region_id)
+        .map(|w| w.weather_type)
+        .unwrap_or(WeatherType::Clear)
+}
+
+/// Medium tick: occasionally shift a region's weather
+#[reducer]
+pub fn tick_weather(ctx: &ReducerContext, region_id: u64) {
+    if !ticks::claim(ctx, region_id, TickKind::Weather) {
+        return;
+    }
+
+    let Some(region) = ctx.db.region().id().find(region_id) else {
+        return;
+    };
+
+    let existing = ctx.db.weather().region_id().find(region_id);
+    if existing.is_some() && ctx.rng().gen::<f32>() >= WEATHER_CHANGE_CHANCE {
+        return;
+    }
+
+    let choices = weather_weights(region.climate);
+    let weights: Vec<u32> = choices.iter().map(|(_, w)| *w).collect();
+    let total = weights.iter().sum();
+    let weather_type = choices[pick_weighted(&weights, ctx.rng().gen_range(0..total))].0;
+
+    let now = ctx.timestamp.to_micros_since_unix_epoch();
+    match existing {
+        Some(current) if current.weather_type == weather_type => return,
+        Some(mut current) => {
+            current.weather_type = weather_type;
+            current.changed_at = now;
+            ctx.db.weather().region_id().update(current);
+        }
+        None => {
+            ctx.db.weather().insert(Weather {
+                region_id,
+                weather_type,
+                changed_at: now,
+            });
+        }
+    }
+
+    for room in ctx.db.room().region_id().filter(region_id) {
+        let event = GameEvent {
+            id: 0,
+            room_id: room.id,
+            timestamp: now,
+            event_type: EventType::Environmental,
+            event_data: format!("{{\"weather\": \"{:?}\"}}", weather_type),
+            primary_actor: 0,
+            secondary_actor: None,
+            requires_sight: true,
+            requires_hearing: false,
+            stealth_dc: None,
+            expires_at: now + 60_000_000,
+        };
+
+        if let Err(e) = ctx.db.game_event().try_insert(event) {
+            log::error!("Failed to create weather event: {:?}", e);
+        }
+    }
+
+    log::info!("Weather in {} is now {:?}", region.name, weather_type);
+}
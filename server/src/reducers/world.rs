@@ -1,14 +1,45 @@
 use crate::tables::{
-    entity::entity, // ← Add trait import (for create_test_entity)
-    region::region, // ← Add trait import
-    room::room,     // ← Add trait import
+    consumable::consumable_effect, // ← Add trait import
+    entity::entity,                // ← Add trait import (for create_test_entity)
+    item_template::item_template,  // ← Add trait import
+    npc_behavior::npc_behavior,    // ← Add trait import
+    recipe::recipe,                // ← Add trait import
+    recipe::recipe_component,      // ← Add trait import
+    region::region,                // ← Add trait import
+    room::room,                    // ← Add trait import
+    shop_stock::shop_stock,        // ← Add trait import
+    spell::spell,                  // ← Add trait import
+    starting_kit::starting_kit,    // ← Add trait import
+    starting_kit::starting_skill,  // ← Add trait import
+    AIType,
+    ArmorType,
     BiomeType,
     ClimateType,
+    ConditionType,
+    ConsumableEffect,
+    ConsumeMethod,
+    DamageType,
     Entity,
     EntityType, // ← Add these for create_test_entity
+    EquipSlot,
+    ItemTemplate,
+    ItemType,
+    MovementType,
+    NPCBehavior,
+    NPCRole,
+    Position,
+    Recipe,
+    RecipeComponent,
     Region,
     Room,
+    ShopStock,
+    SkillType,
+    Spell,
+    SpellTarget,
+    StartingKit,
+    StartingSkill,
 };
+use crate::utils::inventory;
 use spacetimedb::{reducer, ReducerContext, Table};
 use std::collections::HashMap;
 
@@ -165,8 +196,6 @@ pub fn create_room_grid(
 
 #[reducer]
 pub fn create_test_entity(ctx: &ReducerContext, name: String) {
-    use crate::tables::{Entity, EntityType};
-
     log::info!("Creating test entity: {}", name);
 
     let new_entity = Entity {
@@ -194,6 +223,7 @@ pub fn create_test_entity(ctx: &ReducerContext, name: String) {
         perception: 100,
         willpower: 100,
         is_alive: true,
+        position: Position::Standing,
         is_active: true,
         created_at: 0,
         last_action_at: 0,
@@ -205,6 +235,718 @@ pub fn create_test_entity(ctx: &ReducerContext, name: String) {
     }
 }
 
+#[reducer]
+pub fn create_test_shop(ctx: &ReducerContext) -> Result<(), String> {
+    log::info!("Creating test shop");
+
+    let shopkeeper = Entity {
+        id: 0,
+        identity: None,
+        entity_type: EntityType::NPC,
+        name: "Grimsby the Trader".to_string(),
+        description: "A stout merchant with a ledger tucked under one arm.".to_string(),
+        room_id: 1,
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+        volume: 70.0,
+        weight: 80.0,
+        max_capacity: 50.0,
+        hp: 100,
+        max_hp: 100,
+        stamina: 100,
+        max_stamina: 100,
+        mana: 100,
+        max_mana: 100,
+        dexterity: 90,
+        strength: 90,
+        vitality: 100,
+        perception: 110,
+        willpower: 100,
+        is_alive: true,
+        position: Position::Standing,
+        is_active: true,
+        created_at: ctx.timestamp.to_micros_since_unix_epoch(),
+        last_action_at: 0,
+    };
+
+    let shopkeeper = ctx
+        .db
+        .entity()
+        .try_insert(shopkeeper)
+        .map_err(|e| format!("Failed to create shopkeeper: {:?}", e))?;
+
+    let behavior = NPCBehavior {
+        entity_id: shopkeeper.id,
+        ai_type: AIType::Passive,
+        aggro_range: 0,
+        wander_range: 0,
+        home_room: 1,
+        role: Some(NPCRole::Shopkeeper),
+        movement_type: MovementType::Stationary,
+        patrol_waypoints: String::new(),
+        movement_speed: 0.0,
+        faction: None,
+        assist_allies: false,
+        respawn_delay: 300,
+        is_unique: true,
+        can_talk: true,
+        dialogue_tree_id: None,
+        loot_table_id: None,
+    };
+
+    ctx.db
+        .npc_behavior()
+        .try_insert(behavior)
+        .map_err(|e| format!("Failed to create shopkeeper behavior: {:?}", e))?;
+
+    let templates = [
+        ItemTemplate {
+            id: 0,
+            name: "short sword".to_string(),
+            description: "A plain iron blade, sharp enough.".to_string(),
+            item_type: ItemType::Weapon,
+            base_value: 40,
+            weight: 1.5,
+            volume: 1.0,
+            max_stack: 1,
+            base_damage: 12,
+            damage_type: DamageType::Slashing,
+            attack_speed: 1.0,
+            range: 0,
+            ammo_template_id: None,
+            armor_rating: 0,
+            armor_type: ArmorType::Cloth,
+            max_durability: 100,
+            equip_slot: Some(EquipSlot::MainHand),
+        },
+        ItemTemplate {
+            id: 0,
+            name: "leather cap".to_string(),
+            description: "A boiled leather cap.".to_string(),
+            item_type: ItemType::Armor,
+            base_value: 15,
+            weight: 0.5,
+            volume: 0.5,
+            max_stack: 1,
+            base_damage: 0,
+            damage_type: DamageType::Bludgeoning,
+            attack_speed: 0.0,
+            range: 0,
+            ammo_template_id: None,
+            armor_rating: 2,
+            armor_type: ArmorType::Leather,
+            max_durability: 60,
+            equip_slot: Some(EquipSlot::Head),
+        },
+        ItemTemplate {
+            id: 0,
+            name: "loaf of bread".to_string(),
+            description: "A crusty brown loaf.".to_string(),
+            item_type: ItemType::Consumable,
+            base_value: 2,
+            weight: 0.3,
+            volume: 0.3,
+            max_stack: 20,
+            base_damage: 0,
+            damage_type: DamageType::Bludgeoning,
+            attack_speed: 0.0,
+            range: 0,
+            ammo_template_id: None,
+            armor_rating: 0,
+            armor_type: ArmorType::Cloth,
+            max_durability: 0,
+            equip_slot: None,
+        },
+    ];
+
+    let stock = |template: ItemTemplate| {
+        let template = ctx
+            .db
+            .item_template()
+            .try_insert(template)
+            .map_err(|e| format!("Failed to create item template: {:?}", e))?;
+
+        ctx.db
+            .shop_stock()
+            .try_insert(ShopStock {
+                id: 0,
+                shopkeeper_id: shopkeeper.id,
+                template_id: template.id,
+                quantity: 5,
+                max_quantity: 5,
+                restock_amount: 1,
+                last_restock: ctx.timestamp.to_micros_since_unix_epoch(),
+            })
+            .map_err(|e| format!("Failed to stock shop: {:?}", e))?;
+
+        Ok::<u64, String>(template.id)
+    };
+
+    for template in templates {
+        stock(template)?;
+    }
+
+    let arrows = stock(ItemTemplate {
+        damage_type: DamageType::Piercing,
+        base_damage: 4,
+        ..simple_template(
+            "arrow",
+            "A goose-fletched arrow with an iron head.",
+            ItemType::Junk,
+            1,
+            50,
+        )
+    })?;
+
+    stock(ItemTemplate {
+        id: 0,
+        name: "hunting bow".to_string(),
+        description: "A supple yew bow, strung and ready.".to_string(),
+        item_type: ItemType::Weapon,
+        base_value: 35,
+        weight: 1.0,
+        volume: 2.0,
+        max_stack: 1,
+        base_damage: 8,
+        damage_type: DamageType::Piercing,
+        attack_speed: 0.8,
+        range: 3,
+        ammo_template_id: Some(arrows),
+        armor_rating: 0,
+        armor_type: ArmorType::Cloth,
+        max_durability: 80,
+        equip_slot: Some(EquipSlot::TwoHand),
+    })?;
+
+    log::info!("Test shop created with shopkeeper {}", shopkeeper.id);
+    Ok(())
+}
+
+/// Template for a plain item with no combat stats
+fn simple_template(
+    name: &str,
+    description: &str,
+    item_type: ItemType,
+    base_value: u32,
+    max_stack: u32,
+) -> ItemTemplate {
+    ItemTemplate {
+        id: 0,
+        name: name.to_string(),
+        description: description.to_string(),
+        item_type,
+        base_value,
+        weight: 0.5,
+        volume: 0.5,
+        max_stack,
+        base_damage: 0,
+        damage_type: DamageType::Bludgeoning,
+        attack_speed: 0.0,
+        range: 0,
+        ammo_template_id: None,
+        armor_rating: 0,
+        armor_type: ArmorType::Cloth,
+        max_durability: 0,
+        equip_slot: None,
+    }
+}
+
+fn spawn_fixture(
+    ctx: &ReducerContext,
+    name: &str,
+    description: &str,
+    room_id: u64,
+) -> Result<u64, String> {
+    let fixture = Entity {
+        id: 0,
+        identity: None,
+        entity_type: EntityType::Fixture,
+        name: name.to_string(),
+        description: description.to_string(),
+        room_id,
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+        volume: 2.0,
+        weight: 500.0,
+        max_capacity: 0.0,
+        hp: 0,
+        max_hp: 0,
+        stamina: 0,
+        max_stamina: 0,
+        mana: 0,
+        max_mana: 0,
+        dexterity: 0,
+        strength: 0,
+        vitality: 0,
+        perception: 0,
+        willpower: 0,
+        is_alive: true,
+        position: Position::Standing,
+        is_active: true,
+        created_at: ctx.timestamp.to_micros_since_unix_epoch(),
+        last_action_at: 0,
+    };
+
+    ctx.db
+        .entity()
+        .try_insert(fixture)
+        .map(|f| f.id)
+        .map_err(|e| format!("Failed to create {}: {:?}", name, e))
+}
+
+#[reducer]
+pub fn create_test_recipes(ctx: &ReducerContext) -> Result<(), String> {
+    log::info!("Creating test recipes");
+
+    spawn_fixture(ctx, "forge", "A roaring forge fed by bellows.", 1)?;
+    spawn_fixture(
+        ctx,
+        "campfire",
+        "A crackling campfire ringed with stones.",
+        1,
+    )?;
+
+    let insert = |template: ItemTemplate| {
+        ctx.db
+            .item_template()
+            .try_insert(template)
+            .map(|t| t.id)
+            .map_err(|e| format!("Failed to create item template: {:?}", e))
+    };
+
+    let ingot = insert(simple_template(
+        "iron ingot",
+        "A dull grey bar of iron.",
+        ItemType::Junk,
+        5,
+        20,
+    ))?;
+    let hammer = insert(simple_template(
+        "smith's hammer",
+        "A heavy cross-peen hammer.",
+        ItemType::Tool,
+        10,
+        1,
+    ))?;
+    let raw_meat = insert(simple_template(
+        "raw meat",
+        "A slab of uncooked meat.",
+        ItemType::Consumable,
+        1,
+        10,
+    ))?;
+    let cooked_meat = insert(simple_template(
+        "cooked meat",
+        "A charred, juicy slab of meat.",
+        ItemType::Consumable,
+        3,
+        10,
+    ))?;
+    let dagger = insert(ItemTemplate {
+        id: 0,
+        name: "iron dagger".to_string(),
+        description: "A short, hand-forged blade.".to_string(),
+        item_type: ItemType::Weapon,
+        base_value: 25,
+        weight: 0.8,
+        volume: 0.5,
+        max_stack: 1,
+        base_damage: 8,
+        damage_type: DamageType::Piercing,
+        attack_speed: 1.4,
+        range: 0,
+        ammo_template_id: None,
+        armor_rating: 0,
+        armor_type: ArmorType::Cloth,
+        max_durability: 80,
+        equip_slot: Some(EquipSlot::MainHand),
+    })?;
+
+    let dagger_recipe = ctx
+        .db
+        .recipe()
+        .try_insert(Recipe {
+            id: 0,
+            name: "iron dagger".to_string(),
+            skill_type: SkillType::Blacksmithing,
+            difficulty: 60,
+            station: Some("forge".to_string()),
+            output_template_id: dagger,
+            output_quantity: 1,
+        })
+        .map_err(|e| format!("Failed to create recipe: {:?}", e))?;
+
+    let meat_recipe = ctx
+        .db
+        .recipe()
+        .try_insert(Recipe {
+            id: 0,
+            name: "cooked meat".to_string(),
+            skill_type: SkillType::Cooking,
+            difficulty: 40,
+            station: Some("campfire".to_string()),
+            output_template_id: cooked_meat,
+            output_quantity: 1,
+        })
+        .map_err(|e| format!("Failed to create recipe: {:?}", e))?;
+
+    let components = [
+        (dagger_recipe.id, ingot, 2, false),
+        (dagger_recipe.id, hammer, 1, true),
+        (meat_recipe.id, raw_meat, 1, false),
+    ];
+
+    for (recipe_id, template_id, quantity, is_tool) in components {
+        ctx.db
+            .recipe_component()
+            .try_insert(RecipeComponent {
+                id: 0,
+                recipe_id,
+                template_id,
+                quantity,
+                is_tool,
+            })
+            .map_err(|e| format!("Failed to create recipe component: {:?}", e))?;
+    }
+
+    log::info!("Test recipes created successfully!");
+    Ok(())
+}
+
+#[reducer]
+pub fn create_test_consumables(ctx: &ReducerContext) -> Result<(), String> {
+    log::info!("Creating test consumables");
+
+    let mut templates = Vec::new();
+    for (name, description, value) in [
+        ("healing draught", "A small vial of cloudy red liquid.", 20),
+        ("antidote", "A bitter green tonic in a stoppered flask.", 15),
+        (
+            "vial of venom",
+            "A drop of something you should not drink.",
+            10,
+        ),
+    ] {
+        let template = ctx
+            .db
+            .item_template()
+            .try_insert(simple_template(
+                name,
+                description,
+                ItemType::Consumable,
+                value,
+                10,
+            ))
+            .map_err(|e| format!("Failed to create item template: {:?}", e))?;
+        templates.push(template);
+    }
+
+    let effect = |template_id: u64, method: ConsumeMethod| ConsumableEffect {
+        id: 0,
+        template_id,
+        method,
+        heal_hp: 0,
+        heal_stamina: 0,
+        heal_mana: 0,
+        apply_condition: None,
+        magnitude: 0.0,
+        duration_ticks: 0,
+        cure_condition: None,
+        cooldown_secs: 0,
+    };
+
+    let mut effects = vec![
+        ConsumableEffect {
+            heal_hp: 25,
+            apply_condition: Some(ConditionType::Regenerating),
+            magnitude: 2.0,
+            duration_ticks: 10,
+            cooldown_secs: 30,
+            ..effect(templates[0].id, ConsumeMethod::Drink)
+        },
+        ConsumableEffect {
+            cure_condition: Some(ConditionType::Poisoned),
+            cooldown_secs: 10,
+            ..effect(templates[1].id, ConsumeMethod::Drink)
+        },
+        ConsumableEffect {
+            apply_condition: Some(ConditionType::Poisoned),
+            magnitude: 3.0,
+            duration_ticks: 15,
+            ..effect(templates[2].id, ConsumeMethod::Drink)
+        },
+    ];
+
+    // Food from the shop and the cooking recipes, if those have been seeded
+    for template in ctx.db.item_template().iter() {
+        match template.name.as_str() {
+            "loaf of bread" => effects.push(ConsumableEffect {
+                heal_stamina: 20,
+                cooldown_secs: 5,
+                ..effect(template.id, ConsumeMethod::Eat)
+            }),
+            "cooked meat" => effects.push(ConsumableEffect {
+                heal_hp: 10,
+                heal_stamina: 30,
+                cooldown_secs: 5,
+                ..effect(template.id, ConsumeMethod::Eat)
+            }),
+            _ => {}
+        }
+    }
+
+    for effect in effects {
+        ctx.db
+            .consumable_effect()
+            .try_insert(effect)
+            .map_err(|e| format!("Failed to create consumable effect: {:?}", e))?;
+    }
+
+    for template in &templates {
+        inventory::spawn_item(ctx, template, 3, 1)?;
+    }
+
+    log::info!("Test consumables created successfully!");
+    Ok(())
+}
+
+#[reducer]
+pub fn create_test_post_office(ctx: &ReducerContext) -> Result<(), String> {
+    log::info!("Creating test post office");
+
+    let postmaster = ctx
+        .db
+        .entity()
+        .try_insert(Entity {
+            id: 0,
+            identity: None,
+            entity_type: EntityType::NPC,
+            name: "Wendell the Postmaster".to_string(),
+            description: "A thin man in ink-stained sleeves, sorting letters.".to_string(),
+            room_id: 1,
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+            volume: 70.0,
+            weight: 65.0,
+            max_capacity: 50.0,
+            hp: 100,
+            max_hp: 100,
+            stamina: 100,
+            max_stamina: 100,
+            mana: 100,
+            max_mana: 100,
+            dexterity: 100,
+            strength: 80,
+            vitality: 100,
+            perception: 110,
+            willpower: 100,
+            is_alive: true,
+            position: Position::Standing,
+            is_active: true,
+            created_at: ctx.timestamp.to_micros_since_unix_epoch(),
+            last_action_at: 0,
+        })
+        .map_err(|e| format!("Failed to create postmaster: {:?}", e))?;
+
+    ctx.db
+        .npc_behavior()
+        .try_insert(NPCBehavior {
+            entity_id: postmaster.id,
+            ai_type: AIType::Passive,
+            aggro_range: 0,
+            wander_range: 0,
+            home_room: 1,
+            role: Some(NPCRole::Postmaster),
+            movement_type: MovementType::Stationary,
+            patrol_waypoints: String::new(),
+            movement_speed: 0.0,
+            faction: None,
+            assist_allies: false,
+            respawn_delay: 300,
+            is_unique: true,
+            can_talk: true,
+            dialogue_tree_id: None,
+            loot_table_id: None,
+        })
+        .map_err(|e| format!("Failed to create postmaster behavior: {:?}", e))?;
+
+    log::info!("Test post office created successfully!");
+    Ok(())
+}
+
+#[reducer]
+pub fn create_test_starting_kit(ctx: &ReducerContext) -> Result<(), String> {
+    log::info!("Creating test starting kit");
+
+    let templates = [
+        ItemTemplate {
+            id: 0,
+            name: "worn dagger".to_string(),
+            description: "A nicked blade that has seen a few owners.".to_string(),
+            item_type: ItemType::Weapon,
+            base_value: 8,
+            weight: 0.5,
+            volume: 0.3,
+            max_stack: 1,
+            base_damage: 6,
+            damage_type: DamageType::Piercing,
+            attack_speed: 1.4,
+            range: 0,
+            ammo_template_id: None,
+            armor_rating: 0,
+            armor_type: ArmorType::Cloth,
+            max_durability: 50,
+            equip_slot: Some(EquipSlot::MainHand),
+        },
+        ItemTemplate {
+            id: 0,
+            name: "padded tunic".to_string(),
+            description: "Quilted cloth, better than nothing.".to_string(),
+            item_type: ItemType::Armor,
+            base_value: 10,
+            weight: 2.0,
+            volume: 2.0,
+            max_stack: 1,
+            base_damage: 0,
+            damage_type: DamageType::Bludgeoning,
+            attack_speed: 0.0,
+            range: 0,
+            ammo_template_id: None,
+            armor_rating: 2,
+            armor_type: ArmorType::Cloth,
+            max_durability: 60,
+            equip_slot: Some(EquipSlot::Torso),
+        },
+    ];
+
+    for template in templates {
+        let template = ctx
+            .db
+            .item_template()
+            .try_insert(template)
+            .map_err(|e| format!("Failed to create template: {:?}", e))?;
+
+        ctx.db
+            .starting_kit()
+            .try_insert(StartingKit {
+                id: 0,
+                template_id: template.id,
+                quantity: 1,
+                equip: true,
+            })
+            .map_err(|e| format!("Failed to add starting kit item: {:?}", e))?;
+    }
+
+    // Some food too, if the test shop's bread exists
+    if let Some(bread) = ctx
+        .db
+        .item_template()
+        .iter()
+        .find(|t| t.name == "loaf of bread")
+    {
+        ctx.db
+            .starting_kit()
+            .try_insert(StartingKit {
+                id: 0,
+                template_id: bread.id,
+                quantity: 3,
+                equip: false,
+            })
+            .map_err(|e| format!("Failed to add starting kit item: {:?}", e))?;
+    }
+
+    for (skill_type, level) in [
+        (SkillType::MeleeCombat, 10),
+        (SkillType::Tracking, 5),
+        (SkillType::Haggling, 5),
+    ] {
+        ctx.db
+            .starting_skill()
+            .try_insert(StartingSkill {
+                id: 0,
+                skill_type,
+                level,
+            })
+            .map_err(|e| format!("Failed to add starting skill: {:?}", e))?;
+    }
+
+    log::info!("Test starting kit created successfully!");
+    Ok(())
+}
+
+#[reducer]
+pub fn create_test_spells(ctx: &ReducerContext) -> Result<(), String> {
+    log::info!("Creating test spells");
+
+    let spells = [
+        Spell {
+            id: 0,
+            name: "firebolt".to_string(),
+            description: "Hurls a streak of flame that can set the target alight.".to_string(),
+            mana_cost: 15,
+            target_kind: SpellTarget::Single,
+            damage_type: DamageType::Fire,
+            base_damage: 20,
+            apply_condition: Some(ConditionType::Burning),
+            magnitude: 2.0,
+            duration_ticks: 5,
+            cast_time_ms: 1500,
+        },
+        Spell {
+            id: 0,
+            name: "frost nova".to_string(),
+            description: "A burst of cold that freezes everyone nearby.".to_string(),
+            mana_cost: 30,
+            target_kind: SpellTarget::Room,
+            damage_type: DamageType::Ice,
+            base_damage: 12,
+            apply_condition: Some(ConditionType::Frozen),
+            magnitude: 1.0,
+            duration_ticks: 3,
+            cast_time_ms: 2500,
+        },
+        Spell {
+            id: 0,
+            name: "lightning bolt".to_string(),
+            description: "A crack of lightning leaps from your fingers.".to_string(),
+            mana_cost: 20,
+            target_kind: SpellTarget::Single,
+            damage_type: DamageType::Lightning,
+            base_damage: 28,
+            apply_condition: None,
+            magnitude: 0.0,
+            duration_ticks: 0,
+            cast_time_ms: 2000,
+        },
+        Spell {
+            id: 0,
+            name: "mend".to_string(),
+            description: "Knits your wounds together over a short time.".to_string(),
+            mana_cost: 10,
+            target_kind: SpellTarget::Caster,
+            damage_type: DamageType::Fire,
+            base_damage: 0,
+            apply_condition: Some(ConditionType::Regenerating),
+            magnitude: 3.0,
+            duration_ticks: 10,
+            cast_time_ms: 3000,
+        },
+    ];
+
+    for spell in spells {
+        ctx.db
+            .spell()
+            .try_insert(spell)
+            .map_err(|e| format!("Failed to create spell: {:?}", e))?;
+    }
+
+    log::info!("Test spells created successfully!");
+    Ok(())
+}
+
 #[reducer]
 pub fn whoami(ctx: &ReducerContext) {
     log::info!("Your identity is: {:?}", ctx.sender);
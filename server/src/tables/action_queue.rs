@@ -0,0 +1,30 @@
+use crate::tables::HitLocation;
+use spacetimedb::SpacetimeType;
+
+/// An action asked for before the entity was ready, run on the action tick
+#[spacetimedb::table(name = action_queue)]
+pub struct QueuedAction {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+
+    #[index(btree)]
+    pub entity_id: u64,
+
+    pub kind: ActionKind,
+    pub target_id: Option<u64>,
+    pub location: Option<HitLocation>,
+    pub direction: Option<String>,
+    pub spell_id: Option<u64>,
+
+    pub queued_at: i64,
+}
+
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq)]
+pub enum ActionKind {
+    Attack,
+    Move,
+    Cast,
+    Shoot,
+    Taunt,
+}
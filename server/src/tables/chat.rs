@@ -0,0 +1,88 @@
+use spacetimedb::{Identity, SpacetimeType};
+
+#[spacetimedb::table(name = chat_channel)]
+pub struct ChatChannel {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+
+    #[index(btree)]
+    pub name: String,
+
+    pub kind: ChannelKind,
+
+    /// Character that created the channel; None for built-in channels
+    pub owner_id: Option<u64>,
+
+    /// How long messages are kept before the decay shepherd removes them
+    pub history_secs: u32,
+
+    pub created_at: i64,
+}
+
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq)]
+pub enum ChannelKind {
+    /// Anyone can join
+    Public,
+    /// Members are added by the owner
+    Guild,
+    /// Admins and moderators only
+    Admin,
+}
+
+#[spacetimedb::table(name = chat_membership)]
+pub struct ChatMembership {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+
+    #[index(btree)]
+    pub channel_id: u64,
+
+    #[index(btree)]
+    pub entity_id: u64,
+
+    pub joined_at: i64,
+}
+
+#[spacetimedb::table(name = chat_message)]
+pub struct ChatMessage {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+
+    #[index(btree)]
+    pub channel_id: u64,
+
+    pub sender_id: u64,
+    pub sender_name: String,
+    pub text: String,
+    pub sent_at: i64,
+
+    #[index(btree)]
+    pub expires_at: i64,
+}
+
+/// Personal mute list: read_channel leaves out messages from ignored characters
+#[spacetimedb::table(name = chat_ignore)]
+pub struct ChatIgnore {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+
+    #[index(btree)]
+    pub entity_id: u64,
+
+    pub ignored_id: u64,
+}
+
+/// Moderator-imposed silence, applied to the whole account
+#[spacetimedb::table(name = chat_silence)]
+pub struct ChatSilence {
+    #[primary_key]
+    pub identity: Identity,
+
+    pub until: i64,
+    pub moderator: Identity,
+    pub reason: String,
+}
@@ -31,4 +31,6 @@ pub enum ConditionType {
     Stunned,
     Comatose,
     Encumbered,
+    Slowed,
+    Deafened,
 }
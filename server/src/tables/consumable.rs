@@ -0,0 +1,47 @@
+use crate::tables::ConditionType;
+use spacetimedb::SpacetimeType;
+
+/// What happens when an item made from a template is consumed
+#[spacetimedb::table(name = consumable_effect)]
+pub struct ConsumableEffect {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+
+    #[index(btree)]
+    pub template_id: u64,
+
+    pub method: ConsumeMethod,
+
+    pub heal_hp: i32,
+    pub heal_stamina: i32,
+    pub heal_mana: i32,
+
+    pub apply_condition: Option<ConditionType>,
+    pub magnitude: f32,
+    pub duration_ticks: u32,
+
+    pub cure_condition: Option<ConditionType>,
+
+    pub cooldown_secs: u32,
+}
+
+#[spacetimedb::table(name = consumable_cooldown)]
+pub struct ConsumableCooldown {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+
+    #[index(btree)]
+    pub entity_id: u64,
+
+    pub template_id: u64,
+    pub ready_at: i64,
+}
+
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq)]
+pub enum ConsumeMethod {
+    Eat,
+    Drink,
+    Use,
+}
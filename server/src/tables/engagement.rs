@@ -0,0 +1,27 @@
+use spacetimedb::SpacetimeType;
+
+/// An ongoing fight: `entity_id` keeps swinging at `target_id` on the fast tick
+#[spacetimedb::table(name = engagement)]
+pub struct Engagement {
+    #[primary_key]
+    pub entity_id: u64,
+
+    #[index(btree)]
+    pub target_id: u64,
+
+    #[index(btree)]
+    pub room_id: u64,
+
+    pub next_attack_at: i64,
+    pub started_at: i64,
+}
+
+/// Where a blow lands
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq)]
+pub enum HitLocation {
+    Head,
+    Torso,
+    Hands,
+    Legs,
+    Feet,
+}
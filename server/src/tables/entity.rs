@@ -35,6 +35,7 @@ pub struct Entity {
     pub perception: u8,
     pub willpower: u8,
     pub is_alive: bool,
+    pub position: Position,
     pub is_active: bool,
     pub created_at: i64,
     pub last_action_at: i64,
@@ -48,3 +49,11 @@ pub enum EntityType {
     Container,
     Fixture,
 }
+
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq)]
+pub enum Position {
+    Standing,
+    Sitting,
+    Resting,
+    Sleeping,
+}
@@ -0,0 +1,37 @@
+use spacetimedb::SpacetimeType;
+
+/// Append-only record of every change to an entity's carried gold
+#[spacetimedb::table(name = gold_ledger)]
+pub struct GoldLedger {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+
+    #[index(btree)]
+    pub entity_id: u64,
+
+    #[index(btree)]
+    pub timestamp: i64,
+
+    /// Positive for credits, negative for debits
+    pub amount: i64,
+    pub balance_after: u64,
+
+    /// Where the gold came from (credits) or went to (debits)
+    pub flow: GoldFlow,
+    pub counterpart_id: Option<u64>,
+    pub reason: String,
+}
+
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq)]
+pub enum GoldFlow {
+    Shop,
+    Trade,
+    Bank,
+    Loot,
+    Repair,
+    Crafting,
+    Mail,
+    Admin,
+    Group,
+}
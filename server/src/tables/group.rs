@@ -0,0 +1,61 @@
+use spacetimedb::SpacetimeType;
+
+#[spacetimedb::table(name = group)]
+pub struct Group {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+
+    #[index(btree)]
+    pub leader_id: u64,
+
+    pub created_at: i64,
+
+    pub loot_rule: LootRule,
+    /// Gold picked up is divided among the members present
+    pub share_gold: bool,
+    /// Whose turn it is under round robin, counting members present
+    pub loot_turn: u32,
+}
+
+/// Who keeps an item a group member picks up
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq)]
+pub enum LootRule {
+    FreeForAll,
+    RoundRobin,
+}
+
+#[spacetimedb::table(name = group_member)]
+pub struct GroupMember {
+    #[primary_key]
+    pub entity_id: u64,
+
+    #[index(btree)]
+    pub group_id: u64,
+
+    pub joined_at: i64,
+}
+
+/// Pending invitation, cleared when accepted or superseded
+#[spacetimedb::table(name = group_invitation)]
+pub struct GroupInvitation {
+    #[primary_key]
+    pub invitee_id: u64,
+
+    /// 0 while the inviter has no group; it is founded on acceptance
+    #[index(btree)]
+    pub group_id: u64,
+
+    pub inviter_id: u64,
+    pub created_at: i64,
+}
+
+/// Who an entity is trailing behind; they step wherever the leader steps
+#[spacetimedb::table(name = follower)]
+pub struct Follower {
+    #[primary_key]
+    pub entity_id: u64,
+
+    #[index(btree)]
+    pub leader_id: u64,
+}
@@ -0,0 +1,15 @@
+use crate::tables::DamageType;
+
+/// Damage taken multiplier an entity has by nature (e.g. fire elementals vs Fire)
+#[spacetimedb::table(name = innate_resistance)]
+pub struct InnateResistance {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+
+    #[index(btree)]
+    pub entity_id: u64,
+
+    pub damage_type: DamageType,
+    pub multiplier: f32, // 0.0 = immune, 0.5 = resistant, 2.0 = vulnerable
+}
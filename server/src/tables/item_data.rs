@@ -5,7 +5,9 @@ pub struct ItemData {
     #[primary_key]
     pub entity_id: u64,
 
+    pub template_id: Option<u64>,
     pub item_type: ItemType,
+    pub base_value: u32,
 
     pub quantity: u32,
     pub max_stack: u32,
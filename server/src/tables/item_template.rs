@@ -0,0 +1,32 @@
+use crate::tables::{ArmorType, DamageType, EquipSlot, ItemType};
+
+#[spacetimedb::table(name = item_template)]
+pub struct ItemTemplate {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+
+    pub name: String,
+    pub description: String,
+
+    pub item_type: ItemType,
+    pub base_value: u32,
+
+    pub weight: f32,
+    pub volume: f32,
+    pub max_stack: u32,
+
+    pub base_damage: u16,
+    pub damage_type: DamageType,
+    pub attack_speed: f32,
+
+    /// Rooms a launcher can shoot across; 0 for melee weapons
+    pub range: u8,
+    pub ammo_template_id: Option<u64>,
+
+    pub armor_rating: u16,
+    pub armor_type: ArmorType,
+
+    pub max_durability: u16,
+    pub equip_slot: Option<EquipSlot>,
+}
@@ -0,0 +1,32 @@
+#[spacetimedb::table(name = mail)]
+pub struct Mail {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+
+    #[index(btree)]
+    pub recipient_id: u64,
+
+    pub sender_id: u64,
+    pub sender_name: String,
+    pub subject: String,
+    pub body: String,
+    pub sent_at: i64,
+    pub is_read: bool,
+
+    /// Gold held in escrow until claimed at a postmaster
+    pub gold: u32,
+}
+
+/// An item taken from the sender and held until the recipient claims it
+#[spacetimedb::table(name = mail_attachment)]
+pub struct MailAttachment {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+
+    #[index(btree)]
+    pub mail_id: u64,
+
+    pub item_id: u64,
+}
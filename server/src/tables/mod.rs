@@ -1,32 +1,70 @@
 pub mod account;
+pub mod action_queue;
 pub mod bank_account;
+pub mod chat;
 pub mod condition;
+pub mod consumable;
 pub mod containment;
+pub mod engagement;
 pub mod entity;
 pub mod exit;
 pub mod game_event;
+pub mod gold_ledger;
+pub mod group;
+pub mod innate_resistance;
 pub mod item_data;
+pub mod item_template;
+pub mod mail;
 pub mod npc_behavior;
 pub mod player_session;
 pub mod rate_limit;
+pub mod recipe;
 pub mod region;
+pub mod region_tick;
 pub mod room;
 pub mod server_config;
+pub mod shop_stock;
 pub mod skill;
+pub mod spell;
+pub mod starting_kit;
+pub mod stealth;
+pub mod threat;
+pub mod trade;
+pub mod trail;
+pub mod weather;
 
 // Re-export all types and traits
 pub use account::*;
+pub use action_queue::*;
 pub use bank_account::*;
+pub use chat::*;
 pub use condition::*;
+pub use consumable::*;
 pub use containment::*;
+pub use engagement::*;
 pub use entity::*;
 pub use exit::*;
 pub use game_event::*;
+pub use gold_ledger::*;
+pub use group::*;
+pub use innate_resistance::*;
 pub use item_data::*;
+pub use item_template::*;
+pub use mail::*;
 pub use npc_behavior::*;
 pub use player_session::*;
 pub use rate_limit::*;
+pub use recipe::*;
 pub use region::*;
+pub use region_tick::*;
 pub use room::*;
 pub use server_config::*;
+pub use shop_stock::*;
 pub use skill::*;
+pub use spell::*;
+pub use starting_kit::*;
+pub use stealth::*;
+pub use threat::*;
+pub use trade::*;
+pub use trail::*;
+pub use weather::*;
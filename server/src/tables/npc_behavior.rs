@@ -49,6 +49,7 @@ pub enum NPCRole {
     Blacksmith,
     Alchemist,
     Monster,
+    Postmaster,
 }
 
 #[derive(SpacetimeType, Clone, Copy, Debug, PartialEq)]
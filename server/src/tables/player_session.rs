@@ -5,6 +5,9 @@ pub struct PlayerSession {
     #[primary_key]
     pub identity: Identity,
 
+    /// Account that logged in; may differ from the connection identity
+    pub account: Identity,
+
     pub character_id: u64,
     pub connected_at: i64,
     pub last_heartbeat: i64,
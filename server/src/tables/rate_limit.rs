@@ -1,12 +1,19 @@
 use spacetimedb::{Identity, SpacetimeType};
 
+/// One row per (identity, action type), counting actions in fixed windows
+/// that are blended into a sliding window when checked
 #[spacetimedb::table(name = rate_limit)]
 pub struct RateLimit {
     #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+
+    #[index(btree)]
     pub identity: Identity,
 
     pub action_type: ActionType,
     pub count: u32,
+    pub previous_count: u32,
     pub window_start: i64,
     pub last_action: i64,
 }
@@ -18,4 +25,5 @@ pub enum ActionType {
     Speech,
     Command,
     Trade,
+    Track,
 }
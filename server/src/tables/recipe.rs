@@ -0,0 +1,35 @@
+use crate::tables::SkillType;
+
+#[spacetimedb::table(name = recipe)]
+pub struct Recipe {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+
+    pub name: String,
+
+    pub skill_type: SkillType,
+    pub difficulty: u8,
+
+    /// Name of a fixture that must be in the room, e.g. "forge"
+    pub station: Option<String>,
+
+    pub output_template_id: u64,
+    pub output_quantity: u32,
+}
+
+#[spacetimedb::table(name = recipe_component)]
+pub struct RecipeComponent {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+
+    #[index(btree)]
+    pub recipe_id: u64,
+
+    pub template_id: u64,
+    pub quantity: u32,
+
+    /// Tools must be carried but are not used up
+    pub is_tool: bool,
+}
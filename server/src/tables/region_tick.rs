@@ -0,0 +1,26 @@
+use spacetimedb::SpacetimeType;
+
+/// When a region last ran one of its ticks, so ticks can't be driven early
+#[spacetimedb::table(name = region_tick)]
+pub struct RegionTick {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+
+    #[index(btree)]
+    pub region_id: u64,
+
+    pub kind: TickKind,
+    pub last_tick: i64,
+}
+
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq)]
+pub enum TickKind {
+    Actions,
+    Combat,
+    Conditions,
+    Npcs,
+    Regeneration,
+    Tracks,
+    Weather,
+}
@@ -0,0 +1,16 @@
+#[spacetimedb::table(name = shop_stock)]
+pub struct ShopStock {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+
+    #[index(btree)]
+    pub shopkeeper_id: u64,
+
+    pub template_id: u64,
+
+    pub quantity: u32,
+    pub max_quantity: u32,
+    pub restock_amount: u32,
+    pub last_restock: i64,
+}
@@ -0,0 +1,32 @@
+use crate::tables::{ConditionType, DamageType};
+use spacetimedb::SpacetimeType;
+
+#[spacetimedb::table(name = spell)]
+pub struct Spell {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+
+    pub name: String,
+    pub description: String,
+
+    pub mana_cost: i32,
+    pub target_kind: SpellTarget,
+
+    pub damage_type: DamageType,
+    pub base_damage: u16,
+
+    pub apply_condition: Option<ConditionType>,
+    pub magnitude: f32,
+    pub duration_ticks: u32,
+
+    /// Concentration needed before the spell can be released
+    pub cast_time_ms: u32,
+}
+
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq)]
+pub enum SpellTarget {
+    Caster, // Always affects the caster
+    Single, // One other entity in the room
+    Room,   // Everyone else in the room
+}
@@ -0,0 +1,26 @@
+use crate::tables::SkillType;
+
+/// An item every new character is given
+#[spacetimedb::table(name = starting_kit)]
+pub struct StartingKit {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+
+    pub template_id: u64,
+    pub quantity: u32,
+
+    /// Put it on (or in hand) straight away
+    pub equip: bool,
+}
+
+/// A skill every new character starts with
+#[spacetimedb::table(name = starting_skill)]
+pub struct StartingSkill {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+
+    pub skill_type: SkillType,
+    pub level: u8,
+}
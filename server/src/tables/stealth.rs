@@ -0,0 +1,33 @@
+use spacetimedb::SpacetimeType;
+
+/// Entities currently trying not to be noticed
+#[spacetimedb::table(name = stealth_state)]
+pub struct StealthState {
+    #[primary_key]
+    pub entity_id: u64,
+
+    pub mode: StealthMode,
+    pub stealth_dc: u8, // Perception needed to spot them
+    pub since: i64,
+}
+
+/// An observer's perception roll against one hide attempt, kept until the
+/// hider hides again so repeated looks don't get fresh rolls
+#[spacetimedb::table(name = stealth_check)]
+pub struct StealthCheck {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+
+    #[index(btree)]
+    pub hider_id: u64,
+
+    pub observer_id: u64,
+    pub spotted: bool,
+}
+
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq)]
+pub enum StealthMode {
+    Hidden,   // Stays put; moving gives the position away
+    Sneaking, // Moves quietly, rolling again on each step
+}
@@ -0,0 +1,16 @@
+/// How much an NPC wants to hit each entity that has bothered it
+#[spacetimedb::table(name = threat)]
+pub struct Threat {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+
+    #[index(btree)]
+    pub npc_id: u64,
+
+    #[index(btree)]
+    pub entity_id: u64,
+
+    pub amount: f32,
+    pub updated_at: i64,
+}
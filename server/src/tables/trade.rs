@@ -0,0 +1,42 @@
+#[spacetimedb::table(name = trade_session)]
+pub struct TradeSession {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+
+    #[index(btree)]
+    pub initiator_id: u64,
+
+    #[index(btree)]
+    pub partner_id: u64,
+
+    pub room_id: u64,
+
+    pub initiator_gold: u32,
+    pub partner_gold: u32,
+
+    pub initiator_confirmed: bool,
+    pub partner_confirmed: bool,
+
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+#[spacetimedb::table(name = trade_offer)]
+pub struct TradeOffer {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+
+    #[index(btree)]
+    pub trade_id: u64,
+
+    pub owner_id: u64,
+
+    #[index(btree)]
+    pub item_id: u64,
+
+    /// The item as it was when offered; any change resets confirmations
+    pub quantity: u32,
+    pub durability: u16,
+}
@@ -0,0 +1,16 @@
+/// Footprints left in a room by something passing through
+#[spacetimedb::table(name = trail)]
+pub struct Trail {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+
+    #[index(btree)]
+    pub room_id: u64,
+
+    pub entity_id: u64,
+    pub direction: String, // Which way they left
+    pub created_at: i64,
+
+    pub freshness: f32, // 1.0 = fresh, fades to 0.0 and is removed
+}
@@ -0,0 +1,20 @@
+use spacetimedb::SpacetimeType;
+
+/// Current weather in each region, changed by the region shepherd
+#[spacetimedb::table(name = weather)]
+pub struct Weather {
+    #[primary_key]
+    pub region_id: u64,
+
+    pub weather_type: WeatherType,
+    pub changed_at: i64,
+}
+
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq)]
+pub enum WeatherType {
+    Clear,
+    Cloudy,
+    Rain,
+    Storm,
+    Snow,
+}
@@ -0,0 +1,335 @@
+use crate::tables::{
+    engagement::engagement, entity::entity, game_event::game_event, room::room, ConditionType,
+    DamageType, Engagement, Entity, EquipSlot, EventType, GameEvent, HitLocation, ItemData, Room,
+    SkillType,
+};
+use crate::utils::{
+    combat_stats, conditions, groups, inventory, position, resistances, skills, stealth, threat,
+};
+use dogmud_common::{
+    apply_armor, apply_resistance, attack_interval_ms, calculate_base_damage, pick_weighted,
+};
+use spacetimedb::{rand::Rng, ReducerContext, Table};
+
+/// Stamina spent on every swing
+pub const ATTACK_STAMINA_COST: i32 = 10;
+
+/// Where unaimed blows land, out of 100
+const HIT_LOCATIONS: [(HitLocation, u32); 5] = [
+    (HitLocation::Head, 10),
+    (HitLocation::Torso, 45),
+    (HitLocation::Hands, 10),
+    (HitLocation::Legs, 25),
+    (HitLocation::Feet, 10),
+];
+
+pub fn roll_hit_location(ctx: &ReducerContext) -> HitLocation {
+    let weights: Vec<u32> = HIT_LOCATIONS.iter().map(|(_, w)| *w).collect();
+    let total = weights.iter().sum();
+    HIT_LOCATIONS[pick_weighted(&weights, ctx.rng().gen_range(0..total))].0
+}
+
+/// Attack roll modifier for aiming at a body part; small targets are harder
+pub fn aim_modifier(location: HitLocation) -> f32 {
+    match location {
+        HitLocation::Torso => 0.9,
+        HitLocation::Legs => 0.85,
+        HitLocation::Head | HitLocation::Feet => 0.75,
+        HitLocation::Hands => 0.7,
+    }
+}
+
+/// The equipment slot protecting a body part
+pub fn location_slot(location: HitLocation) -> EquipSlot {
+    match location {
+        HitLocation::Head => EquipSlot::Head,
+        HitLocation::Torso => EquipSlot::Torso,
+        HitLocation::Hands => EquipSlot::Hands,
+        HitLocation::Legs => EquipSlot::Legs,
+        HitLocation::Feet => EquipSlot::Feet,
+    }
+}
+
+/// Worn armor covering the part that was hit
+pub fn armor_at(ctx: &ReducerContext, entity_id: u64, location: HitLocation) -> Vec<ItemData> {
+    let slot = location_slot(location);
+    inventory::worn_armor(ctx, entity_id)
+        .into_iter()
+        .filter(|a| a.equipped_slot == Some(slot))
+        .collect()
+}
+
+/// Lasting effects of a critical hit: blows to the head stun, to the legs slow
+pub fn critical_effect(
+    ctx: &ReducerContext,
+    target_id: u64,
+    location: HitLocation,
+    source_id: u64,
+) {
+    let (condition_type, ticks) = match location {
+        HitLocation::Head => (ConditionType::Stunned, 3),
+        HitLocation::Legs | HitLocation::Feet => (ConditionType::Slowed, 10),
+        _ => return,
+    };
+    conditions::apply_condition(ctx, target_id, condition_type, 1.0, ticks, Some(source_id));
+}
+
+/// Every piece that absorbed a blow takes wear
+pub fn wear_armor(ctx: &ReducerContext, target: &Entity, armor: Vec<ItemData>) {
+    for piece in armor.into_iter().filter(|a| a.armor_rating > 0) {
+        if inventory::wear_item(ctx, piece, 1) {
+            log::info!("{}'s armor breaks!", target.name);
+        }
+    }
+}
+
+/// Everything that must hold for `attacker` to swing at `target`
+pub fn check_melee(
+    ctx: &ReducerContext,
+    attacker: &Entity,
+    target: &Entity,
+) -> Result<Room, String> {
+    if !attacker.is_alive {
+        return Err("You are dead".to_string());
+    }
+
+    position::require_awake(attacker)?;
+
+    if conditions::has_condition(ctx, attacker.id, ConditionType::Stunned) {
+        return Err("You are stunned".to_string());
+    }
+
+    if !target.is_alive {
+        return Err("Target is already dead".to_string());
+    }
+
+    if attacker.id == target.id {
+        return Err("You cannot attack yourself".to_string());
+    }
+
+    if attacker.room_id != target.room_id || !stealth::can_see(ctx, attacker, target) {
+        return Err(format!("{} is not here", target.name));
+    }
+
+    let room = ctx
+        .db
+        .room()
+        .id()
+        .find(attacker.room_id)
+        .ok_or("Room not found")?;
+
+    if !room.allows_combat {
+        return Err("Combat is not allowed here".to_string());
+    }
+
+    if attacker.stamina < ATTACK_STAMINA_COST {
+        return Err("Not enough stamina to attack".to_string());
+    }
+
+    Ok(room)
+}
+
+/// Swing once with the wielded weapon (or fists), applying damage, wear and
+/// the combat event. Aiming at a body part costs accuracy. Returns true if
+/// the target died.
+pub fn resolve_melee(
+    ctx: &ReducerContext,
+    mut attacker: Entity,
+    mut target: Entity,
+    aim: Option<HitLocation>,
+) -> Result<bool, String> {
+    // Attacking gives away your position, and nobody sleeps through it
+    stealth::reveal(ctx, &attacker);
+    position::wake(&mut target);
+
+    let attack_stat = ((attacker.dexterity as u16 + attacker.strength as u16) / 2) as u8;
+    let attack_skill = skills::skill_level(ctx, attacker.id, SkillType::MeleeCombat);
+    let attack_modifier = aim.map_or(1.0, aim_modifier);
+    let attack_roll = combat_stats::calculate_roll_base(attack_stat, attack_skill, attack_modifier);
+    let attack_sample = combat_stats::random_variance(attack_roll, ctx);
+
+    let defense_stat = ((target.dexterity as u16 + target.perception as u16) / 2) as u8;
+    let defense_skill = skills::skill_level(ctx, target.id, SkillType::MeleeCombat);
+    let defense_roll = combat_stats::calculate_roll_base(defense_stat, defense_skill, 0.9);
+    let defense_sample = combat_stats::random_variance(defense_roll, ctx);
+
+    let hit = attack_sample > defense_sample;
+    let is_crit = combat_stats::is_critical_hit(attack_sample, defense_sample);
+    let is_fumble = combat_stats::is_critical_fail(attack_sample, defense_sample);
+
+    let weapon = inventory::wielded_weapon(ctx, attacker.id);
+    let weapon_id = weapon.as_ref().map(|w| w.entity_id);
+    let damage_type = weapon
+        .as_ref()
+        .map_or(DamageType::Bludgeoning, |w| w.damage_type);
+
+    let location = aim.unwrap_or_else(|| roll_hit_location(ctx));
+    let mut damage = 0;
+    let mut result_message;
+
+    if is_fumble {
+        result_message = format!("{} fumbles the attack!", attacker.name);
+    } else if !hit {
+        result_message = format!("{} misses {}", attacker.name, target.name);
+    } else {
+        let base_damage = match &weapon {
+            Some(weapon) => {
+                calculate_base_damage(weapon.base_damage, attacker.strength, attack_skill)
+            }
+            None => (attacker.strength as f32 / 10.0) as u16,
+        };
+
+        if let Some(weapon) = weapon {
+            if inventory::wear_item(ctx, weapon, 1) {
+                log::info!("{}'s weapon breaks!", attacker.name);
+            }
+        }
+
+        if is_crit {
+            // Critical hits bypass armor, but not innate resistances
+            let multiplier = resistances::damage_multiplier(ctx, target.id, damage_type, &[]);
+            damage = apply_resistance((base_damage as f32 * 1.3) as u16, multiplier) as i32;
+            critical_effect(ctx, target.id, location, attacker.id);
+            result_message = format!(
+                "{} critically hits {}'s {:?} for {} damage!",
+                attacker.name, target.name, location, damage
+            );
+        } else {
+            let armor = armor_at(ctx, target.id, location);
+            let armor_rating = armor.iter().map(|a| a.armor_rating).sum();
+            let multiplier = resistances::damage_multiplier(ctx, target.id, damage_type, &armor);
+            damage = apply_resistance(apply_armor(base_damage, armor_rating), multiplier) as i32;
+
+            wear_armor(ctx, &target, armor);
+
+            result_message = format!(
+                "{} hits {}'s {:?} for {} damage",
+                attacker.name, target.name, location, damage
+            );
+        }
+
+        target.hp = (target.hp - damage).max(0);
+        threat::add_threat(ctx, target.id, attacker.id, damage as f32);
+
+        if target.hp == 0 {
+            target.is_alive = false;
+            result_message.push_str(&format!(" {} has died!", target.name));
+        }
+    }
+
+    attacker.stamina = (attacker.stamina - ATTACK_STAMINA_COST).max(0);
+    attacker.last_action_at = ctx.timestamp.to_micros_since_unix_epoch();
+
+    let attacker_id = attacker.id;
+    let target_id = target.id;
+    let room_id = attacker.room_id;
+    let died = !target.is_alive;
+    ctx.db.entity().id().update(attacker);
+    ctx.db.entity().id().update(target);
+
+    if died {
+        disengage(ctx, target_id);
+        groups::share_kill(ctx, attacker_id, target_id);
+    }
+
+    let event = GameEvent {
+        id: 0,
+        room_id,
+        timestamp: ctx.timestamp.to_micros_since_unix_epoch(),
+        event_type: EventType::Combat,
+        event_data: format!(
+            "{{\"attacker\": {}, \"target\": {}, \"weapon\": {}, \"location\": \"{:?}\", \"aimed\": {}, \"damage\": {}, \"damage_type\": \"{:?}\", \"hit\": {}, \"critical\": {}}}",
+            attacker_id,
+            target_id,
+            weapon_id.map_or("null".to_string(), |id| id.to_string()),
+            location,
+            aim.is_some(),
+            damage,
+            damage_type,
+            hit,
+            is_crit
+        ),
+        primary_actor: attacker_id,
+        secondary_actor: Some(target_id),
+        requires_sight: true,
+        requires_hearing: true,
+        stealth_dc: None,
+        expires_at: ctx.timestamp.to_micros_since_unix_epoch() + 60_000_000,
+    };
+
+    ctx.db
+        .game_event()
+        .try_insert(event)
+        .map_err(|e| format!("Failed to create event: {:?}", e))?;
+
+    log::info!("Combat: {}", result_message);
+
+    Ok(died)
+}
+
+/// Delay before an entity's next automatic swing
+pub fn attack_interval_micros(ctx: &ReducerContext, entity: &Entity) -> i64 {
+    let attack_speed = inventory::wielded_weapon(ctx, entity.id).map_or(1.0, |w| w.attack_speed);
+    attack_interval_ms(attack_speed, entity.dexterity) as i64 * 1000
+}
+
+pub fn engagement_of(ctx: &ReducerContext, entity_id: u64) -> Option<Engagement> {
+    ctx.db.engagement().entity_id().find(entity_id)
+}
+
+/// Start (or retarget) an entity's auto-attacks
+pub fn engage(ctx: &ReducerContext, attacker: &Entity, target_id: u64) {
+    let now = ctx.timestamp.to_micros_since_unix_epoch();
+    let engagement = Engagement {
+        entity_id: attacker.id,
+        target_id,
+        room_id: attacker.room_id,
+        next_attack_at: now + attack_interval_micros(ctx, attacker),
+        started_at: now,
+    };
+
+    if engagement_of(ctx, attacker.id).is_some() {
+        ctx.db.engagement().entity_id().update(engagement);
+    } else {
+        ctx.db.engagement().insert(engagement);
+    }
+}
+
+/// Living opponents in the same room, on either side of a fight
+pub fn opponents_here(ctx: &ReducerContext, entity: &Entity) -> Vec<Entity> {
+    ctx.db
+        .engagement()
+        .target_id()
+        .filter(entity.id)
+        .map(|e| e.entity_id)
+        .chain(engagement_of(ctx, entity.id).map(|e| e.target_id))
+        .filter_map(|id| ctx.db.entity().id().find(id))
+        .filter(|e| e.is_alive && e.room_id == entity.room_id)
+        .collect()
+}
+
+/// Refuse to simply walk away from a fight; fleeing is the way out
+pub fn require_not_fighting(ctx: &ReducerContext, entity: &Entity) -> Result<(), String> {
+    if opponents_here(ctx, entity).is_empty() {
+        Ok(())
+    } else {
+        Err("You are fighting! Use flee to escape".to_string())
+    }
+}
+
+/// End every fight an entity is part of, on either side
+pub fn disengage(ctx: &ReducerContext, entity_id: u64) {
+    ctx.db.engagement().entity_id().delete(entity_id);
+
+    let attackers: Vec<_> = ctx
+        .db
+        .engagement()
+        .target_id()
+        .filter(entity_id)
+        .map(|e| e.entity_id)
+        .collect();
+
+    for attacker_id in attackers {
+        ctx.db.engagement().entity_id().delete(attacker_id);
+    }
+}
@@ -0,0 +1,75 @@
+use crate::tables::{condition::condition, Condition, ConditionType};
+use spacetimedb::{ReducerContext, Table};
+
+pub fn has_condition(ctx: &ReducerContext, entity_id: u64, condition_type: ConditionType) -> bool {
+    ctx.db
+        .condition()
+        .entity_id()
+        .filter(entity_id)
+        .any(|c| c.condition_type == condition_type)
+}
+
+/// Conditions nobody would want put on them
+pub fn is_hostile(condition_type: ConditionType) -> bool {
+    !matches!(
+        condition_type,
+        ConditionType::Regenerating | ConditionType::Hasted | ConditionType::Blessed
+    )
+}
+
+/// Apply a condition, refreshing an existing one to the stronger/longer values
+pub fn apply_condition(
+    ctx: &ReducerContext,
+    entity_id: u64,
+    condition_type: ConditionType,
+    magnitude: f32,
+    ticks: u32,
+    source_id: Option<u64>,
+) {
+    let existing = ctx
+        .db
+        .condition()
+        .entity_id()
+        .filter(entity_id)
+        .find(|c| c.condition_type == condition_type);
+
+    match existing {
+        Some(mut current) => {
+            current.magnitude = current.magnitude.max(magnitude);
+            current.remaining_ticks = current.remaining_ticks.max(ticks);
+            current.source_id = source_id.or(current.source_id);
+            ctx.db.condition().id().update(current);
+        }
+        None => {
+            ctx.db.condition().insert(Condition {
+                id: 0,
+                entity_id,
+                condition_type,
+                magnitude,
+                remaining_ticks: ticks,
+                source_id,
+            });
+        }
+    }
+}
+
+/// Remove a condition, returning true if the entity had it
+pub fn remove_condition(
+    ctx: &ReducerContext,
+    entity_id: u64,
+    condition_type: ConditionType,
+) -> bool {
+    let ids: Vec<_> = ctx
+        .db
+        .condition()
+        .entity_id()
+        .filter(entity_id)
+        .filter(|c| c.condition_type == condition_type)
+        .map(|c| c.id)
+        .collect();
+
+    for id in &ids {
+        ctx.db.condition().id().delete(*id);
+    }
+    !ids.is_empty()
+}
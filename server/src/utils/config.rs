@@ -0,0 +1,18 @@
+use crate::tables::server_config::server_config;
+use spacetimedb::ReducerContext;
+
+/// Raw ServerConfig value for a key
+pub fn get_value(ctx: &ReducerContext, key: &str) -> Option<String> {
+    ctx.db
+        .server_config()
+        .key()
+        .find(key.to_string())
+        .map(|c| c.value)
+}
+
+/// Numeric ServerConfig value, falling back to `default` if unset or malformed
+pub fn get_f32(ctx: &ReducerContext, key: &str, default: f32) -> f32 {
+    get_value(ctx, key)
+        .and_then(|v| v.trim().parse().ok())
+        .unwrap_or(default)
+}
@@ -0,0 +1,248 @@
+use crate::tables::{
+    entity::entity, group::follower, group::group, group::group_invitation, group::group_member,
+    Entity, EntityType, Follower, Group, GroupMember, LootRule, SkillType,
+};
+use crate::utils::skills;
+use spacetimedb::{ReducerContext, Table};
+
+/// Largest party allowed, leader included
+pub const MAX_GROUP_SIZE: usize = 6;
+
+/// The group an entity belongs to, if any
+pub fn group_of(ctx: &ReducerContext, entity_id: u64) -> Option<Group> {
+    let member = ctx.db.group_member().entity_id().find(entity_id)?;
+    ctx.db.group().id().find(member.group_id)
+}
+
+/// Everyone in a group, longest-standing member first
+pub fn members(ctx: &ReducerContext, group_id: u64) -> Vec<GroupMember> {
+    let mut members: Vec<_> = ctx.db.group_member().group_id().filter(group_id).collect();
+    members.sort_by_key(|m| (m.joined_at, m.entity_id));
+    members
+}
+
+/// The group an invitation from `leader_id` leads into, founding it if the
+/// inviter has none yet
+pub fn found(ctx: &ReducerContext, leader_id: u64) -> Result<Group, String> {
+    match group_of(ctx, leader_id) {
+        Some(group) if group.leader_id == leader_id => Ok(group),
+        Some(_) => Err("They have since joined another group".to_string()),
+        None => {
+            let group = ctx
+                .db
+                .group()
+                .try_insert(Group {
+                    id: 0,
+                    leader_id,
+                    created_at: ctx.timestamp.to_micros_since_unix_epoch(),
+                    loot_rule: LootRule::FreeForAll,
+                    share_gold: true,
+                    loot_turn: 0,
+                })
+                .map_err(|e| format!("Failed to create group: {:?}", e))?;
+            add_member(ctx, group.id, leader_id);
+            Ok(group)
+        }
+    }
+}
+
+pub fn add_member(ctx: &ReducerContext, group_id: u64, entity_id: u64) {
+    ctx.db.group_member().insert(GroupMember {
+        entity_id,
+        group_id,
+        joined_at: ctx.timestamp.to_micros_since_unix_epoch(),
+    });
+}
+
+/// Fall in behind the group leader, dropping whoever the member followed
+pub fn follow_leader(ctx: &ReducerContext, entity_id: u64, leader_id: u64) {
+    ctx.db.follower().entity_id().delete(entity_id);
+
+    // A leader never trails one of their own members
+    if ctx
+        .db
+        .follower()
+        .entity_id()
+        .find(leader_id)
+        .is_some_and(|f| f.leader_id == entity_id)
+    {
+        ctx.db.follower().entity_id().delete(leader_id);
+    }
+
+    ctx.db.follower().insert(Follower {
+        entity_id,
+        leader_id,
+    });
+}
+
+/// Stop an entity trailing `leader_id`, if that's who it follows
+fn stop_following(ctx: &ReducerContext, entity_id: u64, leader_id: u64) {
+    if ctx
+        .db
+        .follower()
+        .entity_id()
+        .find(entity_id)
+        .is_some_and(|f| f.leader_id == leader_id)
+    {
+        ctx.db.follower().entity_id().delete(entity_id);
+    }
+}
+
+/// Take an entity out of its group, handing over leadership or disbanding
+/// the group once nobody is left to lead
+pub fn remove_member(ctx: &ReducerContext, entity_id: u64) {
+    let Some(mut group) = group_of(ctx, entity_id) else {
+        return;
+    };
+
+    ctx.db.group_member().entity_id().delete(entity_id);
+    stop_following(ctx, entity_id, group.leader_id);
+    let remaining = members(ctx, group.id);
+
+    if remaining.len() < 2 {
+        disband(ctx, group.id);
+        return;
+    }
+
+    if group.leader_id == entity_id {
+        group.leader_id = remaining[0].entity_id;
+        stop_following(ctx, group.leader_id, entity_id);
+        for member in &remaining[1..] {
+            if ctx
+                .db
+                .follower()
+                .entity_id()
+                .find(member.entity_id)
+                .is_some_and(|f| f.leader_id == entity_id)
+            {
+                follow_leader(ctx, member.entity_id, group.leader_id);
+            }
+        }
+        ctx.db.group().id().update(group);
+    }
+}
+
+pub fn disband(ctx: &ReducerContext, group_id: u64) {
+    let leader_id = ctx.db.group().id().find(group_id).map(|g| g.leader_id);
+
+    for member in members(ctx, group_id) {
+        ctx.db.group_member().entity_id().delete(member.entity_id);
+        if let Some(leader_id) = leader_id {
+            stop_following(ctx, member.entity_id, leader_id);
+        }
+    }
+
+    let invitations: Vec<_> = ctx
+        .db
+        .group_invitation()
+        .group_id()
+        .filter(group_id)
+        .map(|i| i.invitee_id)
+        .collect();
+    for invitee_id in invitations {
+        ctx.db.group_invitation().invitee_id().delete(invitee_id);
+    }
+
+    ctx.db.group().id().delete(group_id);
+}
+
+/// Living members of the entity's group standing in its room, longest-standing
+/// first; just the entity itself outside a group
+pub fn members_present(ctx: &ReducerContext, entity: &Entity) -> Vec<Entity> {
+    let Some(group) = group_of(ctx, entity.id) else {
+        return ctx.db.entity().id().find(entity.id).into_iter().collect();
+    };
+
+    members(ctx, group.id)
+        .into_iter()
+        .filter_map(|m| ctx.db.entity().id().find(m.entity_id))
+        .filter(|e| e.is_alive && e.room_id == entity.room_id)
+        .collect()
+}
+
+/// Who keeps an item `finder` picks up under their group's loot rule
+pub fn looter(ctx: &ReducerContext, finder: &Entity) -> u64 {
+    let Some(mut group) = group_of(ctx, finder.id) else {
+        return finder.id;
+    };
+
+    if group.loot_rule != LootRule::RoundRobin {
+        return finder.id;
+    }
+
+    let present = members_present(ctx, finder);
+    if present.is_empty() {
+        return finder.id;
+    }
+
+    let looter_id = present[group.loot_turn as usize % present.len()].id;
+    group.loot_turn = group.loot_turn.wrapping_add(1);
+    ctx.db.group().id().update(group);
+    looter_id
+}
+
+/// How gold `finder` picks up is divided: an even share for every member
+/// present if the group shares gold, with the remainder kept by the finder
+pub fn gold_shares(ctx: &ReducerContext, finder: &Entity, amount: u32) -> Vec<(u64, u32)> {
+    let shared = group_of(ctx, finder.id).is_some_and(|g| g.share_gold);
+    if !shared {
+        return vec![(finder.id, amount)];
+    }
+
+    let present = members_present(ctx, finder);
+    if present.is_empty() {
+        return vec![(finder.id, amount)];
+    }
+
+    let share = amount / present.len() as u32;
+    let remainder = amount - share * present.len() as u32;
+
+    present
+        .into_iter()
+        .map(|e| {
+            let bonus = if e.id == finder.id { remainder } else { 0 };
+            (e.id, share + bonus)
+        })
+        .collect()
+}
+
+/// The fighting skill an entity is best at, which is what kill experience trains
+fn fighting_skill(ctx: &ReducerContext, entity_id: u64) -> SkillType {
+    [
+        SkillType::MagicCasting,
+        SkillType::RangedCombat,
+        SkillType::MeleeCombat,
+    ]
+    .into_iter()
+    .max_by_key(|s| skills::skill_level(ctx, entity_id, *s))
+    .unwrap_or(SkillType::MeleeCombat)
+}
+
+/// Experience for killing an NPC, worth its max hp and split evenly among
+/// the killer's group members present
+pub fn share_kill(ctx: &ReducerContext, killer_id: u64, victim_id: u64) {
+    let Some(victim) = ctx.db.entity().id().find(victim_id) else {
+        return;
+    };
+    let Some(killer) = ctx.db.entity().id().find(killer_id) else {
+        return;
+    };
+
+    if victim.entity_type != EntityType::NPC {
+        return;
+    }
+
+    let present = members_present(ctx, &killer);
+    if present.is_empty() {
+        return;
+    }
+
+    let share = victim.max_hp.max(0) as u32 / present.len() as u32;
+
+    for member in present {
+        let skill = fighting_skill(ctx, member.id);
+        if let Some(level) = skills::practice_skill(ctx, member.id, skill, share) {
+            log::info!("{}'s {:?} improves to {}", member.name, skill, level);
+        }
+    }
+}
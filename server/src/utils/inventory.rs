@@ -0,0 +1,290 @@
+use crate::tables::{
+    containment::containment, entity::entity, item_data::item_data, Containment, Entity,
+    EntityType, EquipSlot, ItemData, ItemTemplate, ItemType, Position,
+};
+use spacetimedb::{ReducerContext, Table};
+
+/// Items carried directly by an entity (not inside bags)
+pub fn carried_items(ctx: &ReducerContext, holder_id: u64) -> Vec<ItemData> {
+    ctx.db
+        .containment()
+        .container_id()
+        .filter(holder_id)
+        .filter_map(|c| ctx.db.item_data().entity_id().find(c.contained_id))
+        .collect()
+}
+
+pub fn is_carried_by(ctx: &ReducerContext, item_id: u64, holder_id: u64) -> bool {
+    ctx.db
+        .containment()
+        .contained_id()
+        .filter(item_id)
+        .any(|c| c.container_id == holder_id)
+}
+
+/// Broken gear stays equipped but gives no stats
+pub fn is_broken(item: &ItemData) -> bool {
+    item.max_durability > 0 && item.durability == 0
+}
+
+/// The working weapon an entity fights with, if any
+pub fn wielded_weapon(ctx: &ReducerContext, holder_id: u64) -> Option<ItemData> {
+    carried_items(ctx, holder_id).into_iter().find(|i| {
+        i.is_equipped
+            && i.item_type == ItemType::Weapon
+            && matches!(
+                i.equipped_slot,
+                Some(EquipSlot::MainHand) | Some(EquipSlot::TwoHand)
+            )
+            && !is_broken(i)
+    })
+}
+
+/// Working armor an entity is wearing
+pub fn worn_armor(ctx: &ReducerContext, holder_id: u64) -> Vec<ItemData> {
+    carried_items(ctx, holder_id)
+        .into_iter()
+        .filter(|i| i.is_equipped && i.item_type == ItemType::Armor && !is_broken(i))
+        .collect()
+}
+
+/// Wear down an item, returning true if this broke it
+pub fn wear_item(ctx: &ReducerContext, mut item: ItemData, amount: u16) -> bool {
+    if item.max_durability == 0 || item.durability == 0 {
+        return false;
+    }
+
+    item.durability = item.durability.saturating_sub(amount);
+    let broke = item.durability == 0;
+    ctx.db.item_data().entity_id().update(item);
+    broke
+}
+
+/// Create an item entity from a template, lying on the floor of `room_id`
+pub fn spawn_item(
+    ctx: &ReducerContext,
+    template: &ItemTemplate,
+    quantity: u32,
+    room_id: u64,
+) -> Result<u64, String> {
+    let item = Entity {
+        id: 0,
+        identity: None,
+        entity_type: EntityType::Item,
+        name: template.name.clone(),
+        description: template.description.clone(),
+        room_id,
+        x: 0.0,
+        y: 0.0,
+        z: 0.0,
+        volume: template.volume,
+        weight: template.weight,
+        max_capacity: 0.0,
+        hp: 0,
+        max_hp: 0,
+        stamina: 0,
+        max_stamina: 0,
+        mana: 0,
+        max_mana: 0,
+        dexterity: 0,
+        strength: 0,
+        vitality: 0,
+        perception: 0,
+        willpower: 0,
+        is_alive: true,
+        position: Position::Standing,
+        is_active: true,
+        created_at: ctx.timestamp.to_micros_since_unix_epoch(),
+        last_action_at: 0,
+    };
+
+    let inserted = ctx
+        .db
+        .entity()
+        .try_insert(item)
+        .map_err(|e| format!("Failed to create item: {:?}", e))?;
+
+    let data = ItemData {
+        entity_id: inserted.id,
+        // Ad-hoc templates (like coins) are never stored and keep id 0
+        template_id: Some(template.id).filter(|&id| id != 0),
+        item_type: template.item_type,
+        base_value: template.base_value,
+        quantity,
+        max_stack: template.max_stack,
+        base_damage: template.base_damage,
+        damage_type: template.damage_type,
+        attack_speed: template.attack_speed,
+        armor_rating: template.armor_rating,
+        armor_type: template.armor_type,
+        internal_volume: 0.0,
+        weight_reduction: 0.0,
+        durability: template.max_durability,
+        max_durability: template.max_durability,
+        is_equipped: false,
+        equipped_slot: None,
+    };
+
+    ctx.db
+        .item_data()
+        .try_insert(data)
+        .map_err(|e| format!("Failed to create item data: {:?}", e))?;
+
+    Ok(inserted.id)
+}
+
+/// Move an item into an entity's inventory
+pub fn give_item(ctx: &ReducerContext, item_id: u64, holder_id: u64) -> Result<(), String> {
+    release_item(ctx, item_id);
+
+    let mut item = ctx.db.entity().id().find(item_id).ok_or("Item not found")?;
+
+    // Carried items are not in any room
+    item.room_id = 0;
+    ctx.db.entity().id().update(item);
+
+    ctx.db
+        .containment()
+        .try_insert(Containment {
+            id: 0,
+            container_id: holder_id,
+            contained_id: item_id,
+            depth: 0,
+            slot_index: None,
+        })
+        .map_err(|e| format!("Failed to store item: {:?}", e))?;
+
+    Ok(())
+}
+
+/// Detach an item from whatever holds it
+pub fn release_item(ctx: &ReducerContext, item_id: u64) {
+    let rows: Vec<_> = ctx
+        .db
+        .containment()
+        .contained_id()
+        .filter(item_id)
+        .map(|c| c.id)
+        .collect();
+
+    for id in rows {
+        ctx.db.containment().id().delete(id);
+    }
+}
+
+pub fn destroy_item(ctx: &ReducerContext, item_id: u64) {
+    release_item(ctx, item_id);
+    ctx.db.item_data().entity_id().delete(item_id);
+    ctx.db.entity().id().delete(item_id);
+}
+
+/// Destroy an item along with everything packed inside it
+pub fn destroy_with_contents(ctx: &ReducerContext, item_id: u64) {
+    let contents: Vec<_> = ctx
+        .db
+        .containment()
+        .container_id()
+        .filter(item_id)
+        .map(|c| c.contained_id)
+        .collect();
+
+    for contained_id in contents {
+        destroy_with_contents(ctx, contained_id);
+    }
+
+    destroy_item(ctx, item_id);
+}
+
+/// Give items from a template, topping up an existing stack first
+pub fn give_from_template(
+    ctx: &ReducerContext,
+    template: &ItemTemplate,
+    quantity: u32,
+    holder_id: u64,
+) -> Result<u64, String> {
+    if template.max_stack > 1 {
+        let stack = carried_items(ctx, holder_id)
+            .into_iter()
+            .find(|i| i.template_id == Some(template.id) && i.quantity + quantity <= i.max_stack);
+
+        if let Some(mut stack) = stack {
+            stack.quantity += quantity;
+            let stack_id = stack.entity_id;
+            ctx.db.item_data().entity_id().update(stack);
+            return Ok(stack_id);
+        }
+    }
+
+    let item_id = spawn_item(ctx, template, quantity, 0)?;
+    give_item(ctx, item_id, holder_id)?;
+    Ok(item_id)
+}
+
+/// How many items made from a template an entity carries
+pub fn count_from_template(ctx: &ReducerContext, holder_id: u64, template_id: u64) -> u32 {
+    carried_items(ctx, holder_id)
+        .iter()
+        .filter(|i| i.template_id == Some(template_id))
+        .map(|i| i.quantity)
+        .sum()
+}
+
+/// Use up items made from a template, taking from stacks before destroying them
+pub fn consume_from_template(
+    ctx: &ReducerContext,
+    holder_id: u64,
+    template_id: u64,
+    quantity: u32,
+) -> Result<(), String> {
+    if count_from_template(ctx, holder_id, template_id) < quantity {
+        return Err("You don't have enough materials".to_string());
+    }
+
+    let mut remaining = quantity;
+    for mut item in carried_items(ctx, holder_id)
+        .into_iter()
+        .filter(|i| i.template_id == Some(template_id) && !i.is_equipped)
+    {
+        if remaining == 0 {
+            break;
+        }
+
+        if item.quantity <= remaining {
+            remaining -= item.quantity;
+            destroy_item(ctx, item.entity_id);
+        } else {
+            item.quantity -= remaining;
+            remaining = 0;
+            ctx.db.item_data().entity_id().update(item);
+        }
+    }
+
+    if remaining > 0 {
+        return Err("Unequip your materials first".to_string());
+    }
+
+    Ok(())
+}
+
+/// Total gold coins carried by an entity
+pub fn gold_carried(ctx: &ReducerContext, holder_id: u64) -> u64 {
+    carried_items(ctx, holder_id)
+        .iter()
+        .filter(|i| i.item_type == ItemType::Gold)
+        .map(|i| i.quantity as u64)
+        .sum()
+}
+
+/// Total weight of everything an entity carries
+pub fn carried_weight(ctx: &ReducerContext, holder_id: u64) -> f32 {
+    carried_items(ctx, holder_id)
+        .iter()
+        .filter_map(|i| {
+            ctx.db
+                .entity()
+                .id()
+                .find(i.entity_id)
+                .map(|e| e.weight * i.quantity as f32)
+        })
+        .sum()
+}
@@ -0,0 +1,152 @@
+use crate::tables::{
+    gold_ledger::gold_ledger, item_data::item_data, ArmorType, DamageType, GoldFlow, GoldLedger,
+    ItemTemplate, ItemType,
+};
+use crate::utils::inventory;
+use spacetimedb::{ReducerContext, Table};
+
+// All changes to carried gold go through credit_gold/debit_gold so that
+// every coin entering or leaving the economy is on the ledger.
+
+pub fn credit_gold(
+    ctx: &ReducerContext,
+    entity_id: u64,
+    amount: u32,
+    flow: GoldFlow,
+    counterpart_id: Option<u64>,
+    reason: &str,
+) -> Result<(), String> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    add_gold(ctx, entity_id, amount)?;
+    record(ctx, entity_id, amount as i64, flow, counterpart_id, reason)
+}
+
+pub fn debit_gold(
+    ctx: &ReducerContext,
+    entity_id: u64,
+    amount: u32,
+    flow: GoldFlow,
+    counterpart_id: Option<u64>,
+    reason: &str,
+) -> Result<(), String> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    remove_gold(ctx, entity_id, amount)?;
+    record(
+        ctx,
+        entity_id,
+        -(amount as i64),
+        flow,
+        counterpart_id,
+        reason,
+    )
+}
+
+/// Move gold between two entities, recording both sides
+pub fn transfer_gold(
+    ctx: &ReducerContext,
+    from_id: u64,
+    to_id: u64,
+    amount: u32,
+    flow: GoldFlow,
+    reason: &str,
+) -> Result<(), String> {
+    debit_gold(ctx, from_id, amount, flow, Some(to_id), reason)?;
+    credit_gold(ctx, to_id, amount, flow, Some(from_id), reason)
+}
+
+fn record(
+    ctx: &ReducerContext,
+    entity_id: u64,
+    amount: i64,
+    flow: GoldFlow,
+    counterpart_id: Option<u64>,
+    reason: &str,
+) -> Result<(), String> {
+    ctx.db
+        .gold_ledger()
+        .try_insert(GoldLedger {
+            id: 0,
+            entity_id,
+            timestamp: ctx.timestamp.to_micros_since_unix_epoch(),
+            amount,
+            balance_after: inventory::gold_carried(ctx, entity_id),
+            flow,
+            counterpart_id,
+            reason: reason.to_string(),
+        })
+        .map_err(|e| format!("Failed to record gold movement: {:?}", e))?;
+
+    Ok(())
+}
+
+fn add_gold(ctx: &ReducerContext, holder_id: u64, amount: u32) -> Result<(), String> {
+    let stack = inventory::carried_items(ctx, holder_id)
+        .into_iter()
+        .find(|i| i.item_type == ItemType::Gold && i.quantity.checked_add(amount).is_some());
+
+    if let Some(mut stack) = stack {
+        stack.quantity += amount;
+        ctx.db.item_data().entity_id().update(stack);
+        return Ok(());
+    }
+
+    let gold = ItemTemplate {
+        id: 0,
+        name: "gold coins".to_string(),
+        description: "A pile of gold coins.".to_string(),
+        item_type: ItemType::Gold,
+        base_value: 1,
+        weight: 0.01,
+        volume: 0.001,
+        max_stack: u32::MAX,
+        base_damage: 0,
+        damage_type: DamageType::Bludgeoning,
+        attack_speed: 0.0,
+        range: 0,
+        ammo_template_id: None,
+        armor_rating: 0,
+        armor_type: ArmorType::Cloth,
+        max_durability: 0,
+        equip_slot: None,
+    };
+
+    let gold_id = inventory::spawn_item(ctx, &gold, amount, 0)?;
+    inventory::give_item(ctx, gold_id, holder_id)
+}
+
+fn remove_gold(ctx: &ReducerContext, holder_id: u64, amount: u32) -> Result<(), String> {
+    let carried = inventory::gold_carried(ctx, holder_id);
+    if carried < amount as u64 {
+        return Err(format!(
+            "You need {} gold but only have {}",
+            amount, carried
+        ));
+    }
+
+    let mut remaining = amount;
+    for mut stack in inventory::carried_items(ctx, holder_id)
+        .into_iter()
+        .filter(|i| i.item_type == ItemType::Gold)
+    {
+        if remaining == 0 {
+            break;
+        }
+
+        if stack.quantity <= remaining {
+            remaining -= stack.quantity;
+            inventory::destroy_item(ctx, stack.entity_id);
+        } else {
+            stack.quantity -= remaining;
+            remaining = 0;
+            ctx.db.item_data().entity_id().update(stack);
+        }
+    }
+
+    Ok(())
+}
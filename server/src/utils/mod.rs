@@ -1 +1,18 @@
+pub mod combat;
 pub mod combat_stats;
+pub mod conditions;
+pub mod config;
+pub mod groups;
+pub mod inventory;
+pub mod ledger;
+pub mod npc;
+pub mod position;
+pub mod rate_limits;
+pub mod resistances;
+pub mod rooms;
+pub mod session;
+pub mod skills;
+pub mod stealth;
+pub mod threat;
+pub mod ticks;
+pub mod timing;
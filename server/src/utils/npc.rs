@@ -0,0 +1,16 @@
+use crate::tables::{entity::entity, npc_behavior::npc_behavior, Entity, EntityType, NPCRole};
+use spacetimedb::ReducerContext;
+
+/// A living NPC with the given role in a room
+pub fn find_npc_with_role(ctx: &ReducerContext, room_id: u64, role: NPCRole) -> Option<Entity> {
+    ctx.db.entity().room_id().filter(room_id).find(|e| {
+        e.entity_type == EntityType::NPC
+            && e.is_alive
+            && ctx
+                .db
+                .npc_behavior()
+                .entity_id()
+                .find(e.id)
+                .is_some_and(|b| b.role == Some(role))
+    })
+}
@@ -0,0 +1,29 @@
+use crate::tables::{Entity, Position};
+
+/// Most actions are impossible in your sleep
+pub fn require_awake(entity: &Entity) -> Result<(), String> {
+    if entity.position == Position::Sleeping {
+        return Err("You are asleep".to_string());
+    }
+    Ok(())
+}
+
+/// Jolt a sleeping entity awake, returning true if it was asleep
+pub fn wake(entity: &mut Entity) -> bool {
+    if entity.position != Position::Sleeping {
+        return false;
+    }
+    entity.position = Position::Standing;
+    log::info!("{} wakes up!", entity.name);
+    true
+}
+
+/// Regeneration multiplier for a posture
+pub fn regen_multiplier(position: Position) -> f32 {
+    match position {
+        Position::Standing => 1.0,
+        Position::Sitting => 1.5,
+        Position::Resting => 2.0,
+        Position::Sleeping => 3.0,
+    }
+}
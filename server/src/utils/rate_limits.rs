@@ -0,0 +1,79 @@
+use crate::tables::{rate_limit::rate_limit, ActionType, RateLimit};
+use crate::utils::config;
+use dogmud_common::{advance_window, sliding_window_count};
+use spacetimedb::{ReducerContext, Table};
+
+/// Default (actions, window in ms) for each action type
+fn default_limit(action_type: ActionType) -> (u32, u32) {
+    match action_type {
+        ActionType::Attack => (4, 2_000),
+        ActionType::Move => (8, 2_000),
+        ActionType::Speech => (5, 10_000),
+        ActionType::Command => (20, 2_000),
+        ActionType::Trade => (10, 5_000),
+        ActionType::Track => (1, 5_000),
+    }
+}
+
+/// Limit for an action type, overridable through ServerConfig as
+/// `rate_limit.<Action>.max` and `rate_limit.<Action>.window_ms`
+fn limit_for(ctx: &ReducerContext, action_type: ActionType) -> (f32, i64) {
+    let (max_actions, window_ms) = default_limit(action_type);
+    let max_actions = config::get_f32(
+        ctx,
+        &format!("rate_limit.{:?}.max", action_type),
+        max_actions as f32,
+    );
+    let window_ms = config::get_f32(
+        ctx,
+        &format!("rate_limit.{:?}.window_ms", action_type),
+        window_ms as f32,
+    );
+    (max_actions, window_ms as i64 * 1000)
+}
+
+/// Count an action against the caller's limit, refusing once it is used up
+pub fn check(ctx: &ReducerContext, action_type: ActionType) -> Result<(), String> {
+    let now = ctx.timestamp.to_micros_since_unix_epoch();
+    let (max_actions, window) = limit_for(ctx, action_type);
+
+    let existing = ctx
+        .db
+        .rate_limit()
+        .identity()
+        .filter(ctx.sender)
+        .find(|r| r.action_type == action_type);
+
+    let Some(mut limit) = existing else {
+        ctx.db.rate_limit().insert(RateLimit {
+            id: 0,
+            identity: ctx.sender,
+            action_type,
+            count: 1,
+            previous_count: 0,
+            window_start: now,
+            last_action: now,
+        });
+        return Ok(());
+    };
+
+    let (window_start, previous, current) = advance_window(
+        limit.window_start,
+        limit.previous_count,
+        limit.count,
+        now,
+        window,
+    );
+
+    if sliding_window_count(window_start, previous, current, now, window) + 1.0 > max_actions {
+        return Err("You're acting too fast".to_string());
+    }
+
+    limit.window_start = window_start;
+    limit.previous_count = previous;
+    limit.count = current + 1;
+    limit.last_action = now;
+    ctx.db.rate_limit().id().update(limit);
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use crate::tables::{
+    innate_resistance::innate_resistance, ArmorType, ConditionType, DamageType, ItemData,
+};
+use crate::utils::{conditions, config};
+use spacetimedb::ReducerContext;
+
+/// Extra damage taken while a condition makes the target vulnerable
+const CONDITION_VULNERABILITY: f32 = 1.5;
+
+/// Built-in damage taken multiplier for each armor type
+/// Metal turns blades but conducts lightning and holds the cold
+pub fn default_armor_multiplier(armor_type: ArmorType, damage_type: DamageType) -> f32 {
+    use ArmorType::*;
+    use DamageType::*;
+
+    match (armor_type, damage_type) {
+        (Cloth, Fire) => 1.2,
+        (Cloth, Ice) => 0.9,
+
+        (Leather, Slashing) | (Leather, Bludgeoning) | (Leather, Acid) => 0.9,
+        (Leather, Ice) => 0.8,
+
+        (Chain, Slashing) => 0.7,
+        (Chain, Piercing) | (Chain, Ice) | (Chain, Acid) => 1.1,
+        (Chain, Lightning) => 1.3,
+
+        (Plate, Slashing) => 0.6,
+        (Plate, Piercing) => 0.8,
+        (Plate, Bludgeoning) | (Plate, Fire) => 1.1,
+        (Plate, Ice) | (Plate, Acid) => 1.2,
+        (Plate, Lightning) => 1.5,
+
+        _ => 1.0,
+    }
+}
+
+/// ServerConfig key overriding one cell of the matrix, e.g. "resist.Plate.Lightning"
+pub fn config_key(armor_type: ArmorType, damage_type: DamageType) -> String {
+    format!("resist.{:?}.{:?}", armor_type, damage_type)
+}
+
+/// Armor multiplier, with any ServerConfig override applied
+pub fn armor_multiplier(
+    ctx: &ReducerContext,
+    armor_type: ArmorType,
+    damage_type: DamageType,
+) -> f32 {
+    config::get_f32(
+        ctx,
+        &config_key(armor_type, damage_type),
+        default_armor_multiplier(armor_type, damage_type),
+    )
+}
+
+/// Total multiplier for damage of a type against an entity wearing `armor`
+/// Armor pieces count in proportion to their armor rating; innate resistances
+/// and conditions stack on top
+pub fn damage_multiplier(
+    ctx: &ReducerContext,
+    entity_id: u64,
+    damage_type: DamageType,
+    armor: &[ItemData],
+) -> f32 {
+    let total_rating: f32 = armor.iter().map(|a| a.armor_rating as f32).sum();
+    let mut multiplier = if total_rating > 0.0 {
+        armor
+            .iter()
+            .map(|a| a.armor_rating as f32 * armor_multiplier(ctx, a.armor_type, damage_type))
+            .sum::<f32>()
+            / total_rating
+    } else {
+        1.0
+    };
+
+    for innate in ctx.db.innate_resistance().entity_id().filter(entity_id) {
+        if innate.damage_type == damage_type {
+            multiplier *= innate.multiplier;
+        }
+    }
+
+    let vulnerable_to = match damage_type {
+        DamageType::Lightning => Some(ConditionType::Wet),
+        DamageType::Bludgeoning => Some(ConditionType::Frozen),
+        _ => None,
+    };
+    if vulnerable_to.is_some_and(|c| conditions::has_condition(ctx, entity_id, c)) {
+        multiplier *= CONDITION_VULNERABILITY;
+    }
+
+    multiplier.max(0.0)
+}
@@ -0,0 +1,81 @@
+use crate::tables::{room::room, Room};
+use spacetimedb::ReducerContext;
+use std::collections::{HashMap, VecDeque};
+
+/// Most rooms searched when looking for a way between two rooms
+const MAX_PATH_ROOMS: usize = 500;
+
+/// Canonical name for a direction, accepting the one-letter abbreviations
+pub fn normalize_direction(direction: &str) -> Result<&'static str, String> {
+    match direction.to_lowercase().as_str() {
+        "north" | "n" => Ok("north"),
+        "south" | "s" => Ok("south"),
+        "east" | "e" => Ok("east"),
+        "west" | "w" => Ok("west"),
+        "up" | "u" => Ok("up"),
+        "down" | "d" => Ok("down"),
+        _ => Err(format!("Invalid direction: {}", direction)),
+    }
+}
+
+/// Room on the other side of an exit, if the room has one that way
+pub fn exit_toward(room: &Room, direction: &str) -> Result<Option<u64>, String> {
+    Ok(match normalize_direction(direction)? {
+        "north" => room.north_exit,
+        "south" => room.south_exit,
+        "east" => room.east_exit,
+        "west" => room.west_exit,
+        "up" => room.up_exit,
+        _ => room.down_exit,
+    })
+}
+
+/// Rooms reachable through a room's compass exits
+pub fn exit_rooms(room: &Room) -> impl Iterator<Item = u64> {
+    [
+        room.north_exit,
+        room.south_exit,
+        room.east_exit,
+        room.west_exit,
+        room.up_exit,
+        room.down_exit,
+    ]
+    .into_iter()
+    .flatten()
+}
+
+/// The first room on the shortest walk from `from` to `to` through active
+/// rooms, if there is a way
+pub fn next_step(ctx: &ReducerContext, from: u64, to: u64) -> Option<u64> {
+    // Each room reached, keyed to the first step taken to reach it
+    let mut first_steps: HashMap<u64, u64> = HashMap::new();
+    let mut frontier = VecDeque::from([from]);
+
+    while let Some(room_id) = frontier.pop_front() {
+        if first_steps.len() >= MAX_PATH_ROOMS {
+            return None;
+        }
+
+        let Some(room) = ctx.db.room().id().find(room_id) else {
+            continue;
+        };
+
+        for next in exit_rooms(&room) {
+            if next == from || first_steps.contains_key(&next) {
+                continue;
+            }
+            if !ctx.db.room().id().find(next).is_some_and(|r| r.is_active) {
+                continue;
+            }
+
+            let first = first_steps.get(&room_id).copied().unwrap_or(next);
+            if next == to {
+                return Some(first);
+            }
+            first_steps.insert(next, first);
+            frontier.push_back(next);
+        }
+    }
+
+    None
+}
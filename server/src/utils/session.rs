@@ -0,0 +1,122 @@
+use crate::tables::{
+    account::account, entity::entity, group::follower, player_session::player_session, Account,
+    Entity, PlayerSession,
+};
+use crate::utils::{combat, timing};
+use spacetimedb::ReducerContext;
+
+/// The caller's live session
+pub fn current_session(ctx: &ReducerContext) -> Result<PlayerSession, String> {
+    let session = ctx
+        .db
+        .player_session()
+        .identity()
+        .find(ctx.sender)
+        .ok_or("Not logged in")?;
+
+    if !session.is_active {
+        return Err("Your session has ended; log in again".to_string());
+    }
+
+    Ok(session)
+}
+
+/// The account the caller is logged in as, or the one tied to their
+/// identity before any login
+pub fn caller_account(ctx: &ReducerContext) -> Result<Account, String> {
+    let owner = current_session(ctx)
+        .map(|s| s.account)
+        .unwrap_or(ctx.sender);
+
+    ctx.db
+        .account()
+        .identity()
+        .find(owner)
+        .ok_or_else(|| "No account found. Create account first.".to_string())
+}
+
+/// The character the caller is currently playing
+pub fn current_character(ctx: &ReducerContext) -> Result<Entity, String> {
+    let session = current_session(ctx)?;
+
+    if session.character_id == 0 {
+        return Err("No character selected".to_string());
+    }
+
+    let character = ctx
+        .db
+        .entity()
+        .id()
+        .find(session.character_id)
+        .ok_or("Character not found")?;
+
+    // Guard against a stale selection after a character changes hands or is deleted
+    if character.identity != Some(session.account) {
+        return Err("That character isn't yours".to_string());
+    }
+
+    Ok(character)
+}
+
+/// The caller's account, if it has admin rights
+pub fn require_admin(ctx: &ReducerContext) -> Result<Account, String> {
+    let account = caller_account(ctx)?;
+
+    if !account.is_admin {
+        return Err("Admin privileges required".to_string());
+    }
+
+    Ok(account)
+}
+
+/// The caller's account, if it can moderate (admins always can)
+pub fn require_moderator(ctx: &ReducerContext) -> Result<Account, String> {
+    let account = caller_account(ctx)?;
+
+    if !(account.is_moderator || account.is_admin) {
+        return Err("Moderator privileges required".to_string());
+    }
+
+    Ok(account)
+}
+
+/// Close a session: bank its play time and leave the character linkdead,
+/// out of any fight, queue or follow chain
+pub fn end_session(ctx: &ReducerContext, mut session: PlayerSession) {
+    if !session.is_active {
+        return;
+    }
+
+    let now = ctx.timestamp.to_micros_since_unix_epoch();
+    let character = ctx.db.entity().id().find(session.character_id);
+
+    if let Some(mut account) = ctx.db.account().identity().find(session.account) {
+        let played = ((now - session.connected_at).max(0) / 1_000_000) as u64;
+        account.total_play_time = account.total_play_time.saturating_add(played);
+        ctx.db.account().identity().update(account);
+    }
+
+    if let Some(character) = character {
+        leave_character(ctx, character);
+    }
+
+    session.is_active = false;
+    ctx.db.player_session().identity().update(session);
+}
+
+/// Leave a character linkdead: out of any fight, queue or follow chain
+pub fn leave_character(ctx: &ReducerContext, mut character: Entity) {
+    combat::disengage(ctx, character.id);
+    timing::clear_queue(ctx, character.id);
+    ctx.db.follower().entity_id().delete(character.id);
+
+    character.is_active = false;
+    ctx.db.entity().id().update(character);
+}
+
+/// Close the caller's session, if it has one
+pub fn end_caller_session(ctx: &ReducerContext) {
+    if let Some(session) = ctx.db.player_session().identity().find(ctx.sender) {
+        end_session(ctx, session);
+    }
+}
@@ -0,0 +1,58 @@
+use crate::tables::{skill::skill, Skill, SkillType};
+use dogmud_common::improvement_chance;
+use spacetimedb::{rand::Rng, ReducerContext, Table};
+
+/// Current level of a skill, 0 if the entity has never trained it
+pub fn skill_level(ctx: &ReducerContext, entity_id: u64, skill_type: SkillType) -> u8 {
+    ctx.db
+        .skill()
+        .entity_id()
+        .filter(entity_id)
+        .find(|s| s.skill_type == skill_type)
+        .map(|s| s.level)
+        .unwrap_or(0)
+}
+
+/// Record a use of a skill and roll for improvement
+/// Returns the new level if the skill went up
+pub fn practice_skill(
+    ctx: &ReducerContext,
+    entity_id: u64,
+    skill_type: SkillType,
+    experience: u32,
+) -> Option<u8> {
+    let now = ctx.timestamp.to_micros_since_unix_epoch();
+
+    let mut skill = ctx
+        .db
+        .skill()
+        .entity_id()
+        .filter(entity_id)
+        .find(|s| s.skill_type == skill_type)
+        .unwrap_or_else(|| {
+            ctx.db.skill().insert(Skill {
+                id: 0,
+                entity_id,
+                skill_type,
+                level: 0,
+                experience: 0,
+                last_used: now,
+                times_used: 0,
+            })
+        });
+
+    skill.experience = skill.experience.saturating_add(experience);
+    skill.times_used = skill.times_used.saturating_add(1);
+    skill.last_used = now;
+
+    let improved =
+        skill.level < u8::MAX && ctx.rng().gen::<f32>() < improvement_chance(skill.level);
+    if improved {
+        skill.level += 1;
+    }
+
+    let level = skill.level;
+    ctx.db.skill().id().update(skill);
+
+    improved.then_some(level)
+}
@@ -0,0 +1,131 @@
+use crate::tables::{
+    game_event::game_event, stealth::stealth_check, stealth::stealth_state, Entity, EventType,
+    GameEvent, Position, SkillType, StealthCheck, StealthMode, StealthState,
+};
+use crate::utils::{combat_stats, skills};
+use spacetimedb::{ReducerContext, Table};
+
+pub fn current_state(ctx: &ReducerContext, entity_id: u64) -> Option<StealthState> {
+    ctx.db.stealth_state().entity_id().find(entity_id)
+}
+
+/// Roll Stealth with dexterity, giving the DC observers must beat
+pub fn roll_stealth(ctx: &ReducerContext, entity: &Entity) -> u8 {
+    let skill = skills::skill_level(ctx, entity.id, SkillType::Stealth);
+    let roll = combat_stats::calculate_roll_base(entity.dexterity, skill, 1.0);
+    combat_stats::random_variance(roll, ctx).clamp(0.0, u8::MAX as f32) as u8
+}
+
+/// Start a new hide attempt: roll a fresh DC and forget every observer's
+/// roll against the old one. Returns the DC
+pub fn hide(ctx: &ReducerContext, entity: &Entity, mode: StealthMode) -> u8 {
+    let stealth_dc = roll_stealth(ctx, entity);
+    let state = StealthState {
+        entity_id: entity.id,
+        mode,
+        stealth_dc,
+        since: ctx.timestamp.to_micros_since_unix_epoch(),
+    };
+
+    if current_state(ctx, entity.id).is_some() {
+        ctx.db.stealth_state().entity_id().update(state);
+    } else {
+        ctx.db.stealth_state().insert(state);
+    }
+
+    forget_checks(ctx, entity.id);
+    stealth_dc
+}
+
+/// Drop every observer's roll against a hider
+pub fn forget_checks(ctx: &ReducerContext, hider_id: u64) {
+    let checks: Vec<_> = ctx
+        .db
+        .stealth_check()
+        .hider_id()
+        .filter(hider_id)
+        .map(|c| c.id)
+        .collect();
+
+    for id in checks {
+        ctx.db.stealth_check().id().delete(id);
+    }
+}
+
+/// Perception check against a stealth DC
+pub fn notices(ctx: &ReducerContext, observer: &Entity, stealth_dc: u8) -> bool {
+    if observer.position == Position::Sleeping {
+        return false;
+    }
+    let roll = combat_stats::calculate_roll_base(observer.perception, 0, 1.0);
+    combat_stats::random_variance(roll, ctx) >= stealth_dc as f32
+}
+
+/// Whether `observer` can see `entity` right now
+/// Each observer rolls once per hide attempt; that contested roll is also
+/// what trains the hider's Stealth
+pub fn can_see(ctx: &ReducerContext, observer: &Entity, entity: &Entity) -> bool {
+    let Some(state) = current_state(ctx, entity.id) else {
+        return true;
+    };
+
+    if observer.position == Position::Sleeping {
+        return false;
+    }
+
+    let previous = ctx
+        .db
+        .stealth_check()
+        .hider_id()
+        .filter(entity.id)
+        .find(|c| c.observer_id == observer.id);
+    if let Some(check) = previous {
+        return check.spotted;
+    }
+
+    let spotted = notices(ctx, observer, state.stealth_dc);
+    ctx.db.stealth_check().insert(StealthCheck {
+        id: 0,
+        hider_id: entity.id,
+        observer_id: observer.id,
+        spotted,
+    });
+
+    if let Some(level) = skills::practice_skill(ctx, entity.id, SkillType::Stealth, 5) {
+        log::info!("{}'s Stealth improves to {}", entity.name, level);
+    }
+
+    spotted
+}
+
+/// Drop out of hiding or sneaking, announcing it if the entity was hidden
+pub fn reveal(ctx: &ReducerContext, entity: &Entity) {
+    let Some(state) = current_state(ctx, entity.id) else {
+        return;
+    };
+    ctx.db.stealth_state().entity_id().delete(entity.id);
+    forget_checks(ctx, entity.id);
+
+    if state.mode != StealthMode::Hidden {
+        return;
+    }
+
+    let event = GameEvent {
+        id: 0,
+        room_id: entity.room_id,
+        timestamp: ctx.timestamp.to_micros_since_unix_epoch(),
+        event_type: EventType::Emote,
+        event_data: format!("{{\"action\": \"reveal\", \"entity_id\": {}}}", entity.id),
+        primary_actor: entity.id,
+        secondary_actor: None,
+        requires_sight: true,
+        requires_hearing: false,
+        stealth_dc: None,
+        expires_at: ctx.timestamp.to_micros_since_unix_epoch() + 60_000_000,
+    };
+
+    if let Err(e) = ctx.db.game_event().try_insert(event) {
+        log::error!("Failed to create reveal event: {:?}", e);
+    }
+    log::info!("{} is no longer hidden", entity.name);
+}
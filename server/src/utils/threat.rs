@@ -0,0 +1,147 @@
+use crate::tables::{entity::entity, npc_behavior::npc_behavior, threat::threat, Entity, Threat};
+use spacetimedb::{ReducerContext, Table};
+
+/// Threat generated per point of healing, relative to damage
+const HEALING_THREAT: f32 = 0.5;
+
+/// Threat kept from one medium tick to the next
+const THREAT_DECAY: f32 = 0.9;
+
+/// Entries that decay below this are forgotten
+pub const MIN_THREAT: f32 = 1.0;
+
+/// Raise `entity_id`'s standing on an NPC's threat list; ignored for non-NPCs
+pub fn add_threat(ctx: &ReducerContext, npc_id: u64, entity_id: u64, amount: f32) {
+    if npc_id == entity_id || amount <= 0.0 {
+        return;
+    }
+
+    if ctx.db.npc_behavior().entity_id().find(npc_id).is_none() {
+        return;
+    }
+
+    let now = ctx.timestamp.to_micros_since_unix_epoch();
+    let existing = ctx
+        .db
+        .threat()
+        .npc_id()
+        .filter(npc_id)
+        .find(|t| t.entity_id == entity_id);
+
+    match existing {
+        Some(mut threat) => {
+            threat.amount += amount;
+            threat.updated_at = now;
+            ctx.db.threat().id().update(threat);
+        }
+        None => {
+            ctx.db.threat().insert(Threat {
+                id: 0,
+                npc_id,
+                entity_id,
+                amount,
+                updated_at: now,
+            });
+        }
+    }
+}
+
+/// Healing someone angers every NPC that is fighting them
+pub fn add_healing_threat(ctx: &ReducerContext, healer_id: u64, healed_id: u64, amount: i32) {
+    if amount <= 0 {
+        return;
+    }
+
+    let npcs: Vec<_> = ctx
+        .db
+        .threat()
+        .entity_id()
+        .filter(healed_id)
+        .map(|t| t.npc_id)
+        .collect();
+
+    for npc_id in npcs {
+        add_threat(ctx, npc_id, healer_id, amount as f32 * HEALING_THREAT);
+    }
+}
+
+/// Highest threat on an NPC's list, if any
+pub fn top_threat(ctx: &ReducerContext, npc_id: u64) -> Option<Threat> {
+    threat_list(ctx, npc_id).into_iter().next()
+}
+
+/// An NPC's threat list, highest first; ties go to whoever got on it first
+pub fn threat_list(ctx: &ReducerContext, npc_id: u64) -> Vec<Threat> {
+    let mut list: Vec<_> = ctx.db.threat().npc_id().filter(npc_id).collect();
+    list.sort_by(|a, b| b.amount.total_cmp(&a.amount).then(a.id.cmp(&b.id)));
+    list
+}
+
+/// The living entity in the NPC's room it most wants to attack
+pub fn pick_target(ctx: &ReducerContext, npc: &Entity) -> Option<Entity> {
+    threat_list(ctx, npc.id).into_iter().find_map(|t| {
+        ctx.db
+            .entity()
+            .id()
+            .find(t.entity_id)
+            .filter(|e| e.is_alive && e.room_id == npc.room_id)
+    })
+}
+
+/// How much an NPC hates one entity
+pub fn threat_from(ctx: &ReducerContext, npc_id: u64, entity_id: u64) -> f32 {
+    ctx.db
+        .threat()
+        .npc_id()
+        .filter(npc_id)
+        .find(|t| t.entity_id == entity_id)
+        .map_or(0.0, |t| t.amount)
+}
+
+/// Let an NPC's grudges fade, dropping the dead and the nearly forgiven
+pub fn decay_threat(ctx: &ReducerContext, npc_id: u64) {
+    for mut entry in threat_list(ctx, npc_id) {
+        entry.amount *= THREAT_DECAY;
+
+        let gone = ctx
+            .db
+            .entity()
+            .id()
+            .find(entry.entity_id)
+            .is_none_or(|e| !e.is_alive);
+
+        if gone || entry.amount < MIN_THREAT {
+            ctx.db.threat().id().delete(entry.id);
+        } else {
+            ctx.db.threat().id().update(entry);
+        }
+    }
+}
+
+/// Forget an entity in every NPC's threat list
+pub fn forget_entity(ctx: &ReducerContext, entity_id: u64) {
+    let rows: Vec<_> = ctx
+        .db
+        .threat()
+        .entity_id()
+        .filter(entity_id)
+        .map(|t| t.id)
+        .collect();
+    for id in rows {
+        ctx.db.threat().id().delete(id);
+    }
+}
+
+/// Forget everyone an NPC was angry with
+pub fn clear_threat(ctx: &ReducerContext, npc_id: u64) {
+    let ids: Vec<_> = ctx
+        .db
+        .threat()
+        .npc_id()
+        .filter(npc_id)
+        .map(|t| t.id)
+        .collect();
+    for id in ids {
+        ctx.db.threat().id().delete(id);
+    }
+}
@@ -0,0 +1,63 @@
+use crate::tables::{region::region, region_tick::region_tick, Region, RegionTick, TickKind};
+use crate::utils::session;
+use spacetimedb::{ReducerContext, Table};
+
+/// Ticks may only be driven by the module itself or an admin (the shepherds)
+pub fn require_ticker(ctx: &ReducerContext) -> Result<(), String> {
+    if ctx.sender == ctx.identity() {
+        return Ok(());
+    }
+
+    session::require_admin(ctx).map(|_| ())
+}
+
+/// Time (ms) a region must wait between runs of a tick
+fn interval_ms(kind: TickKind, region: &Region) -> u32 {
+    match kind {
+        TickKind::Actions | TickKind::Combat | TickKind::Conditions => region.tick_rate_fast,
+        TickKind::Npcs | TickKind::Regeneration | TickKind::Tracks | TickKind::Weather => {
+            region.tick_rate_medium
+        }
+    }
+}
+
+/// Claim a region's tick: false if the caller may not drive ticks or a full
+/// interval hasn't passed since the last run
+pub fn claim(ctx: &ReducerContext, region_id: u64, kind: TickKind) -> bool {
+    if let Err(e) = require_ticker(ctx) {
+        log::warn!("Refused {:?} tick for region {}: {}", kind, region_id, e);
+        return false;
+    }
+
+    let Some(region) = ctx.db.region().id().find(region_id) else {
+        return false;
+    };
+
+    let now = ctx.timestamp.to_micros_since_unix_epoch();
+    let interval = interval_ms(kind, &region) as i64 * 1000;
+
+    let last = ctx
+        .db
+        .region_tick()
+        .region_id()
+        .filter(region_id)
+        .find(|t| t.kind == kind);
+
+    match last {
+        Some(tick) if now - tick.last_tick < interval => false,
+        Some(mut tick) => {
+            tick.last_tick = now;
+            ctx.db.region_tick().id().update(tick);
+            true
+        }
+        None => {
+            ctx.db.region_tick().insert(RegionTick {
+                id: 0,
+                region_id,
+                kind,
+                last_tick: now,
+            });
+            true
+        }
+    }
+}
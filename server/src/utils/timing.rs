@@ -0,0 +1,129 @@
+use crate::tables::{
+    action_queue::action_queue, spell::spell, ActionKind, ConditionType, Entity, QueuedAction,
+};
+use crate::utils::{combat, conditions, inventory};
+use dogmud_common::move_delay_ms;
+use spacetimedb::{ReducerContext, Table};
+
+/// Actions an entity may have waiting at once
+const MAX_QUEUED: usize = 3;
+
+/// Minimum time between steps while Slowed
+const SLOWED_STEP_MICROS: i64 = 2_000_000;
+
+/// A blank queue entry of the given kind, for filling in with struct update
+pub fn action(kind: ActionKind) -> QueuedAction {
+    QueuedAction {
+        id: 0,
+        entity_id: 0,
+        kind,
+        target_id: None,
+        location: None,
+        direction: None,
+        spell_id: None,
+        queued_at: 0,
+    }
+}
+
+/// How long a step takes under the entity's current load
+pub fn move_duration(ctx: &ReducerContext, entity: &Entity) -> i64 {
+    let load = if entity.max_capacity > 0.0 {
+        inventory::carried_weight(ctx, entity.id) / entity.max_capacity
+    } else {
+        0.0
+    };
+
+    let duration = move_delay_ms(load) as i64 * 1000;
+
+    // A wounded leg needs a moment between steps
+    if conditions::has_condition(ctx, entity.id, ConditionType::Slowed) {
+        duration.max(SLOWED_STEP_MICROS)
+    } else {
+        duration
+    }
+}
+
+/// How long an action takes
+pub fn duration(ctx: &ReducerContext, entity: &Entity, action: &QueuedAction) -> i64 {
+    match action.kind {
+        ActionKind::Attack | ActionKind::Shoot | ActionKind::Taunt => {
+            combat::attack_interval_micros(ctx, entity)
+        }
+        ActionKind::Move => move_duration(ctx, entity),
+        ActionKind::Cast => action
+            .spell_id
+            .and_then(|id| ctx.db.spell().id().find(id))
+            .map_or(0, |s| s.cast_time_ms as i64 * 1000),
+    }
+}
+
+/// Microseconds until an action of `duration` can start
+pub fn ready_in(ctx: &ReducerContext, entity: &Entity, duration: i64) -> i64 {
+    let now = ctx.timestamp.to_micros_since_unix_epoch();
+    (entity.last_action_at + duration - now).max(0)
+}
+
+/// Microseconds until a queued action can start. Other actions count from
+/// the entity's previous one, but a spell needs its full concentration from
+/// when it was asked for, however long the caster had been idle
+pub fn action_ready_in(ctx: &ReducerContext, entity: &Entity, action: &QueuedAction) -> i64 {
+    let duration = duration(ctx, entity, action);
+    match action.kind {
+        ActionKind::Cast => {
+            let now = ctx.timestamp.to_micros_since_unix_epoch();
+            let start = entity.last_action_at.max(action.queued_at);
+            (start + duration - now).max(0)
+        }
+        _ => ready_in(ctx, entity, duration),
+    }
+}
+
+pub fn queued(ctx: &ReducerContext, entity_id: u64) -> Vec<QueuedAction> {
+    let mut queue: Vec<_> = ctx
+        .db
+        .action_queue()
+        .entity_id()
+        .filter(entity_id)
+        .collect();
+    queue.sort_by_key(|a| a.id);
+    queue
+}
+
+/// Run the action now if the entity is ready and has nothing waiting,
+/// otherwise put it at the back of the entity's queue. Returns true if the
+/// caller should perform it immediately.
+pub fn ready_or_queue(
+    ctx: &ReducerContext,
+    entity: &Entity,
+    action: QueuedAction,
+) -> Result<bool, String> {
+    let action = QueuedAction {
+        entity_id: entity.id,
+        queued_at: ctx.timestamp.to_micros_since_unix_epoch(),
+        ..action
+    };
+
+    let waiting = queued(ctx, entity.id);
+    if waiting.is_empty() && action_ready_in(ctx, entity, &action) == 0 {
+        return Ok(true);
+    }
+
+    if waiting.len() >= MAX_QUEUED {
+        return Err("You can't plan that far ahead".to_string());
+    }
+
+    let kind = action.kind;
+    ctx.db.action_queue().insert(action);
+
+    log::info!("{} will {:?} when ready", entity.name, kind);
+    Ok(false)
+}
+
+/// Drop everything an entity had waiting
+pub fn clear_queue(ctx: &ReducerContext, entity_id: u64) -> usize {
+    let ids: Vec<_> = queued(ctx, entity_id).into_iter().map(|a| a.id).collect();
+    for id in &ids {
+        ctx.db.action_queue().id().delete(*id);
+    }
+    ids.len()
+}
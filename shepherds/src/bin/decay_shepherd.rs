@@ -1,5 +1,6 @@
 ﻿use anyhow::Result;
 use dogmud_shepherds::SpacetimeClient;
+use serde_json::json;
 use tokio::time::{interval, Duration};
 
 #[tokio::main]
@@ -20,6 +21,12 @@ async fn main() -> Result<()> {
     loop {
         slow_tick.tick().await;
         log::debug!("Slow tick (60s)");
+        if let Err(e) = client.call_reducer("sweep_sessions", json!({})).await {
+            log::error!("sweep_sessions failed: {}", e);
+        }
+        if let Err(e) = client.call_reducer("expire_chat_history", json!({})).await {
+            log::error!("expire_chat_history failed: {}", e);
+        }
         // TODO: Call cleanup_old_events, decay_corpses reducers
     }
 }
@@ -1,5 +1,6 @@
 ﻿use anyhow::Result;
 use dogmud_shepherds::SpacetimeClient;
+use serde_json::json;
 use tokio::time::{interval, Duration};
 
 #[tokio::main]
@@ -27,12 +28,33 @@ async fn main() -> Result<()> {
         tokio::select! {
             _ = fast_tick.tick() => {
                 log::debug!("Fast tick (1s) for region {}", region_id);
-                // TODO: Call tick_conditions reducer
-                // client.call_reducer(\"tick_conditions\", json!({ \"region_id\": region_id })).await?;
+                if let Err(e) = client.call_reducer("tick_conditions", json!({ "region_id": region_id })).await {
+                    log::error!("tick_conditions failed for region {}: {}", region_id, e);
+                }
+                if let Err(e) = client.call_reducer("tick_actions", json!({ "region_id": region_id })).await {
+                    log::error!("tick_actions failed for region {}: {}", region_id, e);
+                }
+                if let Err(e) = client.call_reducer("tick_combat", json!({ "region_id": region_id })).await {
+                    log::error!("tick_combat failed for region {}: {}", region_id, e);
+                }
             }
             _ = medium_tick.tick() => {
                 log::debug!("Medium tick (5s) for region {}", region_id);
-                // TODO: Call tick_npcs, tick_weather reducers
+                if let Err(e) = client.call_reducer("tick_npcs", json!({ "region_id": region_id })).await {
+                    log::error!("tick_npcs failed for region {}: {}", region_id, e);
+                }
+                if let Err(e) = client.call_reducer("tick_weather", json!({ "region_id": region_id })).await {
+                    log::error!("tick_weather failed for region {}: {}", region_id, e);
+                }
+                if let Err(e) = client.call_reducer("tick_regeneration", json!({ "region_id": region_id })).await {
+                    log::error!("tick_regeneration failed for region {}: {}", region_id, e);
+                }
+                if let Err(e) = client.call_reducer("tick_tracks", json!({ "region_id": region_id })).await {
+                    log::error!("tick_tracks failed for region {}: {}", region_id, e);
+                }
+                if let Err(e) = client.call_reducer("tick_shops", json!({ "region_id": region_id })).await {
+                    log::error!("tick_shops failed for region {}: {}", region_id, e);
+                }
             }
         }
     }
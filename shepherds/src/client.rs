@@ -20,7 +20,13 @@ impl SpacetimeClient {
     }
 
     pub async fn call_reducer(&self, name: &str, args: serde_json::Value) -> Result<()> {
-        log::info!("Calling reducer: {} with args: {:?}", name, args);
+        log::info!(
+            "Calling reducer: {} on {}/{} with args: {:?}",
+            name,
+            self.url,
+            self.module,
+            args
+        );
 
         // TODO: Implement actual reducer call using spacetimedb-sdk
 